@@ -0,0 +1,172 @@
+//! Allocation accounting per step, available when the runner is built with the `alloc-stats`
+//! feature, which installs a counting global allocator:
+//!
+//! ```text
+//! cargo run --release -p aoc --features alloc-stats -- 2
+//! ```
+//!
+//! Counters are kept per thread, so steps running in parallel on other threads don't show up in
+//! each other's numbers.
+
+use std::fmt;
+
+/// Allocations made while running one step.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// Highest amount of memory allocated by the step and not yet freed at the same time.
+    pub peak: u64,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} allocs, {} total, {} peak", self.allocations, Bytes(self.bytes), Bytes(self.peak))
+    }
+}
+
+struct Bytes(u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit + 1 < UNITS.len() {
+            value /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{value:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use super::AllocStats;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    #[derive(Clone, Copy)]
+    struct Counters {
+        allocations: u64,
+        bytes: u64,
+        /// Signed, as memory allocated on another thread may be freed on this one.
+        live: i64,
+        peak: i64,
+    }
+
+    thread_local! {
+        static COUNTERS: Cell<Counters> = const { Cell::new(Counters { allocations: 0, bytes: 0, live: 0, peak: 0 }) };
+    }
+
+    fn update(change: impl FnOnce(&mut Counters)) {
+        // The thread local is gone while a thread shuts down, its last frees are not worth counting.
+        let _ = COUNTERS.try_with(|counters| {
+            let mut current = counters.get();
+            change(&mut current);
+            current.peak = current.peak.max(current.live);
+            counters.set(current);
+        });
+    }
+
+    pub struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            update(|counters| {
+                counters.allocations += 1;
+                counters.bytes += layout.size() as u64;
+                counters.live += layout.size() as i64;
+            });
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            update(|counters| {
+                counters.allocations += 1;
+                counters.bytes += layout.size() as u64;
+                counters.live += layout.size() as i64;
+            });
+            unsafe { System.alloc_zeroed(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            update(|counters| counters.live -= layout.size() as i64);
+            unsafe { System.dealloc(ptr, layout) }
+        }
+
+        /// A reallocation counts as a new allocation of the new size replacing the old one.
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            update(|counters| {
+                counters.allocations += 1;
+                counters.bytes += new_size as u64;
+                counters.live += new_size as i64 - layout.size() as i64;
+            });
+            unsafe { System.realloc(ptr, layout, new_size) }
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    pub fn track<T>(step: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+        let before = COUNTERS.with(|counters| {
+            let mut current = counters.get();
+            current.peak = current.live;
+            counters.set(current);
+            current
+        });
+        let result = step();
+        let after = COUNTERS.with(Cell::get);
+        let stats = AllocStats {
+            allocations: after.allocations - before.allocations,
+            bytes: after.bytes - before.bytes,
+            peak: (after.peak - before.live).max(0) as u64,
+        };
+        (result, Some(stats))
+    }
+}
+
+/// Runs `step` and returns its allocations, or `None` when the counting allocator is not built in.
+pub fn track<T>(step: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    return counting::track(step);
+    #[cfg(not(feature = "alloc-stats"))]
+    (step(), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_units() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 << 20).to_string(), "3.0 MiB");
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn counts_allocations_of_the_step() {
+        let (_, stats) = track(|| {
+            let first = vec![0u8; 1000];
+            drop(first);
+            let second = vec![0u8; 600];
+            std::hint::black_box(second)
+        });
+        let stats = stats.unwrap();
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.bytes, 1600);
+        assert_eq!(stats.peak, 1000);
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    #[test]
+    fn nothing_to_count_without_the_allocator() {
+        assert_eq!(track(|| 1), (1, None));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::records;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use utils::answer::Answer;
+
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.txt";
+
+/// Identifies an input file by its content, so answers follow the input wherever it is stored.
+pub fn input_id(input: &str) -> String {
+    // FNV-1a, stable across platforms and Rust versions unlike `DefaultHasher`.
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verification {
+    Correct,
+    Wrong { expected: Answer },
+    Unverified,
+    /// The part ran out of time, so there is no answer to check.
+    TimedOut,
+}
+
+impl fmt::Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verification::Correct => write!(f, "verified"),
+            Verification::Wrong { expected } => write!(f, "WRONG, expected {expected}"),
+            Verification::Unverified => write!(f, "unverified"),
+            Verification::TimedOut => write!(f, "timed out"),
+        }
+    }
+}
+
+/// Accepted answers per day, part and input, stored as `day part input-id answer` lines.
+#[derive(Debug, Default)]
+pub struct KnownAnswers {
+    answers: BTreeMap<(u8, u8, String), Answer>,
+}
+
+impl KnownAnswers {
+    /// Loads the answers file; a missing file simply means nothing is known yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        records::load(path, Self::parse)
+    }
+
+    fn parse(content: &str) -> Result<Self, String> {
+        let answers = records::parse(content, "input")?
+            .into_iter()
+            .map(|record| ((record.day, record.part, record.key.to_string()), record.answer))
+            .collect();
+        Ok(KnownAnswers { answers })
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    pub fn verify(&self, day: u8, part: u8, input_id: &str, answer: &Answer) -> Verification {
+        match self.answers.get(&(day, part, input_id.to_string())) {
+            Some(expected) if expected == answer => Verification::Correct,
+            Some(expected) => Verification::Wrong { expected: expected.clone() },
+            None => Verification::Unverified,
+        }
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, input_id: &str, answer: Answer) {
+        self.answers.insert((day, part, input_id.to_string()), answer);
+    }
+}
+
+impl fmt::Display for KnownAnswers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day part input answer")?;
+        for ((day, part, input_id), answer) in &self.answers {
+            writeln!(f, "{day} {part} {input_id} {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut known = KnownAnswers::default();
+        known.record(7, 1, "00ff", Answer::from(21u64));
+        known.record(7, 2, "00ff", Answer::from("ABC DEF"));
+        known.record(8, 1, "00ff", Answer::from("0123"));
+        let parsed = KnownAnswers::parse(&known.to_string()).unwrap();
+        assert_eq!(parsed.answers, known.answers);
+        assert_eq!(parsed.verify(8, 1, "00ff", &Answer::from("0123")), Verification::Correct);
+    }
+
+    #[test]
+    fn verification() {
+        let mut known = KnownAnswers::default();
+        known.record(8, 1, "abcd", Answer::from(40usize));
+        assert_eq!(known.verify(8, 1, "abcd", &Answer::from(40u64)), Verification::Correct);
+        assert_eq!(
+            known.verify(8, 1, "abcd", &Answer::from(41u64)),
+            Verification::Wrong { expected: Answer::from(40u64) }
+        );
+        assert_eq!(known.verify(8, 2, "abcd", &Answer::from(40u64)), Verification::Unverified);
+        assert_eq!(known.verify(8, 1, "other", &Answer::from(40u64)), Verification::Unverified);
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        assert!(KnownAnswers::parse("# comment\n\n1 2 ff 3\n").is_ok());
+        assert_eq!(KnownAnswers::parse("1 2 ff").unwrap_err(), "line 1: expected `day part input answer`");
+    }
+}
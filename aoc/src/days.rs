@@ -0,0 +1,197 @@
+use crate::allocations::{self, AllocStats};
+use crate::progress::ProgressBars;
+use crate::report::PART_NAMES;
+use crate::timing::{self, Timings};
+use rayon::prelude::*;
+use std::sync::Arc;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+use utils::answer::Answer;
+use utils::cancel::{self, CancelToken};
+use utils::params::{Param, Params};
+use utils::parse::ParseError;
+use utils::progress::{self, ProgressHook};
+use utils::solution::Solution;
+
+pub struct PartResult {
+    pub part: u8,
+    /// `None` when the part ran out of time.
+    pub answer: Option<Answer>,
+    pub timings: Timings,
+    /// Allocations of the last iteration, when the runner counts them.
+    pub allocations: Option<AllocStats>,
+}
+
+pub struct DayRun {
+    pub parse: Timings,
+    pub parse_allocations: Option<AllocStats>,
+    pub results: Vec<PartResult>,
+}
+
+/// How to run the parts of a day.
+pub struct RunOptions<'a> {
+    /// How many times to repeat each step.
+    pub iterations: usize,
+    /// Progress bars to show parts on, with the label prefix of their bars.
+    pub progress: Option<(&'a Arc<ProgressBars>, &'a str)>,
+    /// Budget of each part, all iterations included.
+    pub time_limit: Option<Duration>,
+}
+
+type Solver = fn(&str, &Params, &[u8], &RunOptions) -> Result<DayRun, ParseError>;
+
+pub struct Day {
+    pub number: u8,
+    pub params: &'static [Param],
+    /// Parses the input and solves the requested parts.
+    pub solve: Solver,
+}
+
+impl Day {
+    pub fn name(&self) -> String {
+        format!("day{:02}", self.number)
+    }
+
+    /// The day's parameters: example or real defaults, then those of `overrides` it declares.
+    pub fn params(&self, example: bool, overrides: &Params) -> Result<Params, String> {
+        let mut declared_overrides = Params::default();
+        for (name, value) in overrides.iter() {
+            if self.params.iter().any(|param| param.name == name) {
+                declared_overrides.set(name, value);
+            }
+        }
+        let mut params = Params::defaults(self.params, example);
+        params.apply(&declared_overrides, self.params)?;
+        Ok(params)
+    }
+}
+
+fn solve<S: Solution>(input: &str, params: &Params, parts: &[u8], options: &RunOptions) -> Result<DayRun, ParseError> {
+    let ((parsed, parse_allocations), parse) =
+        timing::measure(options.iterations, || allocations::track(|| S::parse(input, params)));
+    let parsed = Arc::new(parsed.map_err(|err| err.in_day(S::DAY))?);
+    let results = parts
+        .par_iter()
+        .map(|&part| match options.progress {
+            Some((bars, prefix)) => {
+                let bar = bars.bar(format!("{prefix} part {}", PART_NAMES[part as usize - 1]));
+                run_part::<S>(&parsed, part, options, Some(bar.hook()))
+            }
+            None => run_part::<S>(&parsed, part, options, None),
+        })
+        .collect();
+    Ok(DayRun { parse, parse_allocations, results })
+}
+
+/// Runs one part, on its own thread when it has a time limit so that a part ignoring its
+/// cancellation token is abandoned rather than waited for.
+fn run_part<S: Solution>(
+    parsed: &Arc<S::Input>,
+    part: u8,
+    options: &RunOptions,
+    hook: Option<Arc<dyn ProgressHook>>,
+) -> PartResult {
+    let token = CancelToken::new();
+    let iterations = options.iterations;
+    let run = {
+        let parsed = parsed.clone();
+        let token = token.clone();
+        move || {
+            let measure = || {
+                timing::measure(iterations, || {
+                    allocations::track(|| -> Answer {
+                        match part {
+                            1 => S::part_one(&parsed).into(),
+                            _ => S::part_two(&parsed).into(),
+                        }
+                    })
+                })
+            };
+            cancel::with_token(token, || match hook {
+                Some(hook) => progress::with_hook(hook, measure),
+                None => measure(),
+            })
+        }
+    };
+
+    let Some(limit) = options.time_limit else {
+        let ((answer, allocations), timings) = run();
+        return PartResult { part, answer: Some(answer), timings, allocations };
+    };
+    let (sender, receiver) = mpsc::channel();
+    let handle = std::thread::spawn(move || {
+        let _ = sender.send(run());
+    });
+    match receiver.recv_timeout(limit) {
+        Ok(((answer, allocations), timings)) => PartResult { part, answer: Some(answer), timings, allocations },
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            PartResult { part, answer: None, timings: Timings::single(limit), allocations: None }
+        }
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(panic) => std::panic::resume_unwind(panic),
+            Ok(()) => unreachable!("the part thread exited without sending its result"),
+        },
+    }
+}
+
+const fn day<S: Solution>() -> Day {
+    Day { number: S::DAY, params: S::PARAMS, solve: solve::<S> }
+}
+
+pub const DAYS: [Day; 12] = [
+    day::<day01::Day01>(),
+    day::<day02::Day02>(),
+    day::<day03::Day03>(),
+    day::<day04::Day04>(),
+    day::<day05::Day05>(),
+    day::<day06::Day06>(),
+    day::<day07::Day07>(),
+    day::<day08::Day08>(),
+    day::<day09::Day09>(),
+    day::<day10::Day10>(),
+    day::<day11::Day11>(),
+    day::<day12::Day12>(),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Spins until cancelled, or forever in part two.
+    struct Slow;
+
+    impl Solution for Slow {
+        const DAY: u8 = 0;
+
+        type Input = ();
+        type PartOne = u8;
+        type PartTwo = u8;
+
+        fn parse(_input: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+
+        fn part_one(_input: &Self::Input) -> u8 {
+            let cancel = utils::cancel::current();
+            while !cancel.is_cancelled() {
+                std::hint::spin_loop();
+            }
+            1
+        }
+
+        fn part_two(_input: &Self::Input) -> u8 {
+            loop {
+                std::thread::sleep(Duration::from_millis(10));
+            }
+        }
+    }
+
+    #[test]
+    fn parts_time_out() {
+        let options = RunOptions { iterations: 1, progress: None, time_limit: Some(Duration::from_millis(20)) };
+        let run = solve::<Slow>("", &Params::default(), &[1, 2], &options).unwrap();
+        assert!(run.results.iter().all(|result| result.answer.is_none()));
+        assert_eq!(run.results[0].timings.median(), Duration::from_millis(20));
+    }
+}
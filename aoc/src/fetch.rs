@@ -0,0 +1,223 @@
+//! Talks to the puzzle server. Inputs are downloaded into a local cache, so each input is fetched
+//! at most once and the runner keeps working offline afterwards.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2025;
+
+/// The HTTP requests the fetcher needs, so tests can stand in for the puzzle server.
+pub trait HttpClient {
+    /// GETs `url` with the session cookie, returning the body of a successful response.
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError>;
+
+    /// POSTs `form` to `url` with the session cookie, returning the body of a successful response.
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, FetchError>;
+}
+
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+impl Default for UreqClient {
+    fn default() -> Self {
+        UreqClient { agent: ureq::Agent::new_with_defaults() }
+    }
+}
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+fn read_body(response: Result<ureq::http::Response<ureq::Body>, ureq::Error>) -> Result<String, FetchError> {
+    let mut response = response.map_err(|err| match err {
+        ureq::Error::StatusCode(status) => FetchError::Status(status),
+        err => FetchError::Network(err.to_string()),
+    })?;
+    response.body_mut().read_to_string().map_err(|err| FetchError::Network(err.to_string()))
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError> {
+        read_body(
+            self.agent
+                .get(url)
+                .header("Cookie", format!("session={session}"))
+                .header("User-Agent", USER_AGENT)
+                .call(),
+        )
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, FetchError> {
+        read_body(
+            self.agent
+                .post(url)
+                .header("Cookie", format!("session={session}"))
+                .header("User-Agent", USER_AGENT)
+                .send_form(form.iter().copied()),
+        )
+    }
+}
+
+/// The session cookie from `$AOC_SESSION`, if set.
+pub fn session_from_env() -> Option<String> {
+    let session = std::env::var(SESSION_VAR).ok()?;
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession,
+    Status(u16),
+    Network(String),
+    Io(PathBuf, std::io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::NoSession => write!(f, "set {SESSION_VAR} to the session cookie of the puzzle website"),
+            FetchError::Status(404) => write!(f, "the puzzle is not available yet (HTTP 404)"),
+            FetchError::Status(status) => write!(f, "the server answered HTTP {status}, is {SESSION_VAR} still valid?"),
+            FetchError::Network(err) => write!(f, "cannot reach the puzzle server: {err}"),
+            FetchError::Io(path, err) => write!(f, "cannot cache the input in {}: {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+pub struct Fetcher<C> {
+    client: C,
+    base_url: String,
+    cache_dir: PathBuf,
+    session: Option<String>,
+}
+
+impl Fetcher<UreqClient> {
+    /// A fetcher for the real server, caching in `$AOC_CACHE_DIR` or `.aoc-cache` in the workspace.
+    pub fn from_env() -> Self {
+        let cache_dir = match std::env::var_os(CACHE_DIR_VAR) {
+            Some(dir) => PathBuf::from(dir),
+            None => utils::input::workspace_root().join(".aoc-cache"),
+        };
+        Fetcher::new(UreqClient::default(), BASE_URL, cache_dir, session_from_env())
+    }
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    pub fn new(client: C, base_url: &str, cache_dir: PathBuf, session: Option<String>) -> Self {
+        Fetcher { client, base_url: base_url.trim_end_matches('/').to_string(), cache_dir, session }
+    }
+
+    pub fn cached_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day{day:02}.txt"))
+    }
+
+    /// The input of `day`, from the cache when it was downloaded before.
+    pub fn input(&self, day: u8) -> Result<String, FetchError> {
+        let path = self.cached_path(day);
+        if let Ok(input) = std::fs::read_to_string(&path) {
+            return Ok(input);
+        }
+        let session = self.session.as_deref().ok_or(FetchError::NoSession)?;
+        let input = self.client.get(&format!("{}/{YEAR}/day/{day}/input", self.base_url), session)?;
+        write_atomically(&path, &input).map_err(|err| FetchError::Io(path, err))?;
+        Ok(input)
+    }
+}
+
+/// Writes through a temporary file, so an interrupted download never leaves a truncated input.
+fn write_atomically(path: &Path, content: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let partial = path.with_extension("part");
+    std::fs::write(&partial, content)?;
+    std::fs::rename(&partial, path)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    pub(crate) fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// Stands in for the puzzle server on a local port: answers every request with `status` and
+    /// `body`, and records each request as its request line, cookie and body.
+    pub(crate) fn serve(status: &'static str, body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = requests.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = vec![];
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end().to_lowercase();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some(length) = line.strip_prefix("content-length: ") {
+                        content_length = length.parse().unwrap();
+                    }
+                    if line.starts_with("get") || line.starts_with("post") || line.starts_with("cookie") {
+                        request.push(line);
+                    }
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                if content_length > 0 {
+                    request.push(String::from_utf8(content).unwrap());
+                }
+                recorded.lock().unwrap().push(request.join("\n"));
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    #[test]
+    fn downloads_once_then_uses_the_cache() {
+        let (url, requests) = serve("200 OK", "L68\nL30\n");
+        let cache_dir = temp_dir("cache");
+        let fetcher = Fetcher::new(UreqClient::default(), &url, cache_dir.clone(), Some("secret".to_string()));
+        assert_eq!(fetcher.input(1).unwrap(), "L68\nL30\n");
+        assert_eq!(fetcher.input(1).unwrap(), "L68\nL30\n");
+        assert_eq!(*requests.lock().unwrap(), ["get /2025/day/1/input http/1.1\ncookie: session=secret"]);
+        assert_eq!(std::fs::read_to_string(cache_dir.join("day01.txt")).unwrap(), "L68\nL30\n");
+
+        // Offline, with neither a server nor a session, the cache still answers.
+        let offline = Fetcher::new(UreqClient::default(), "http://127.0.0.1:1", cache_dir.clone(), None);
+        assert_eq!(offline.input(1).unwrap(), "L68\nL30\n");
+        assert!(matches!(offline.input(2), Err(FetchError::NoSession)));
+        std::fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn failed_downloads_are_not_cached() {
+        let (url, _) = serve("404 Not Found", "Please don't repeatedly request this endpoint before it unlocks!");
+        let cache_dir = temp_dir("missing");
+        let fetcher = Fetcher::new(UreqClient::default(), &url, cache_dir.clone(), Some("secret".to_string()));
+        assert!(matches!(fetcher.input(25), Err(FetchError::Status(404))));
+        assert!(!fetcher.cached_path(25).exists());
+    }
+}
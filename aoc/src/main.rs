@@ -0,0 +1,485 @@
+mod allocations;
+mod answers;
+mod days;
+mod fetch;
+mod progress;
+mod records;
+mod report;
+mod scaffold;
+mod selection;
+mod submit;
+mod timing;
+mod watch;
+
+use answers::{KnownAnswers, Verification};
+use clap::Parser;
+use days::{DayRun, RunOptions};
+use fetch::Fetcher;
+use rayon::prelude::*;
+use report::{FailedRow, Format, JobError, Reporter, Row, Summary};
+use progress::ProgressBars;
+use std::io::IsTerminal;
+use submit::{Attempt, Check, History, Outcome, Submitter};
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use utils::input::{InputError, InputSource};
+use utils::params::Params;
+use utils::parse::ParseError;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2025 solutions", args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Days to run, e.g. `7`, `3-5` or `1,4,10-12` (default: every day)
+    days: Option<selection::DaySelection>,
+
+    /// Only run the given part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file to use instead of `$AOC_INPUT_DIR/dayNN.txt`, `-` reads stdin (single day only)
+    #[arg(short, long)]
+    input: Option<String>,
+
+    /// Directory with one subdirectory of `dayNN.txt` inputs per person, e.g. `inputs/alice/day07.txt`
+    #[arg(long, conflicts_with = "input")]
+    inputs: Option<PathBuf>,
+
+    /// Run against the day's `example.txt` with the example parameter defaults
+    #[arg(long, conflicts_with_all = ["input", "inputs"])]
+    example: bool,
+
+    /// Override a puzzle parameter, e.g. `--param connections=2000` (repeatable)
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    params: Vec<Params>,
+
+    /// Known answers file (default: answers.txt in the workspace root)
+    #[arg(long)]
+    answers: Option<PathBuf>,
+
+    /// Record the produced answers as accepted, replacing any previously known ones
+    #[arg(long)]
+    record: bool,
+
+    /// Submit the answers that are not known yet to the puzzle website (single day only)
+    #[arg(long, conflicts_with_all = ["example", "inputs", "record", "watch"])]
+    submit: bool,
+
+    /// Log of submitted answers and their verdicts (default: attempts.txt in the workspace root)
+    #[arg(long)]
+    attempts: Option<PathBuf>,
+
+    /// Report parse and part durations, optionally only for some days (e.g. `--time=8,9`)
+    #[arg(long, require_equals = true, num_args = 0..=1)]
+    time: Option<Option<selection::DaySelection>>,
+
+    /// How many times to repeat parsing and each part when timing
+    #[arg(long, default_value_t = 1)]
+    iterations: usize,
+
+    /// Give up on a part after this many seconds, e.g. `--timeout 2.5`, and report it as timed out
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+
+    /// Report what had to be normalized in each input (BOM, CRLF, trailing whitespace)
+    #[arg(long)]
+    show_normalization: bool,
+
+    /// Number of worker threads running days and parts in parallel (default: one per CPU, or one
+    /// with `--time` so that steps do not compete for the CPU while being timed)
+    #[arg(short, long)]
+    jobs: Option<usize>,
+
+    /// Re-run the day's tests and parts whenever its sources or input change (single day only)
+    #[arg(long, conflicts_with_all = ["inputs", "record", "format"])]
+    watch: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+fn parse_seconds(arg: &str) -> Result<Duration, String> {
+    let seconds: f64 = arg.parse().map_err(|err| format!("{err}"))?;
+    Duration::try_from_secs_f64(seconds).map_err(|err| format!("{err}"))
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Create the crate of a new day and register it with the workspace and the runner
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Dependencies besides utils, e.g. `--with petgraph,itertools`
+        #[arg(long = "with", value_delimiter = ',')]
+        dependencies: Vec<scaffold::Dependency>,
+    },
+}
+
+/// A day to solve against one input.
+struct Job<'a> {
+    day: &'a days::Day,
+    input_set: Option<String>,
+    /// Day name and input set, e.g. `day07 (alice)`.
+    label: String,
+    input: String,
+    input_id: String,
+    params: Params,
+    custom_params: bool,
+    timed: bool,
+}
+
+/// Names and paths of the input sets, i.e. the subdirectories of `dir`, sorted by name.
+fn input_sets(dir: &Path) -> std::io::Result<Vec<(String, PathBuf)>> {
+    let mut sets = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            sets.push((entry.file_name().to_string_lossy().into_owned(), entry.path()));
+        }
+    }
+    sets.sort();
+    Ok(sets)
+}
+
+/// The inputs `day` runs against, labelled with their input set when comparing several.
+fn input_sources(args: &Args, day: &days::Day, sets: &[(String, PathBuf)]) -> Vec<(Option<String>, InputSource)> {
+    if args.inputs.is_some() {
+        sets.iter()
+            .map(|(name, dir)| (name, dir.join(format!("{}.txt", day.name()))))
+            .filter(|(_, path)| path.exists())
+            .map(|(name, path)| (Some(name.clone()), InputSource::File(path)))
+            .collect()
+    } else if args.example {
+        let path = utils::input::workspace_root().join(day.name()).join("src").join("example.txt");
+        vec![(None, InputSource::File(path))]
+    } else {
+        vec![(None, InputSource::resolve(day.number, args.input.as_deref()))]
+    }
+}
+
+/// The message a panic was raised with, as passed to `panic!`.
+fn panic_message(panic: &(dyn std::any::Any + Send)) -> String {
+    match (panic.downcast_ref::<&str>(), panic.downcast_ref::<String>()) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "unknown panic".to_string(),
+    }
+}
+
+fn main() {
+    let args = Args::parse();
+    if let Some(Command::New { day, dependencies }) = &args.command {
+        if let Err(err) = scaffold::new_day(*day, dependencies) {
+            eprintln!("{err}");
+            std::process::exit(2);
+        }
+        println!("created day{day:02}: add its example to src/example.txt and the answers to src/manifest.txt");
+        return;
+    }
+    let single_day = args.days.as_ref().is_some_and(|selection| selection.0.len() == 1);
+    if args.input.is_some() && !single_day {
+        eprintln!("--input can only be used when running a single day");
+        std::process::exit(2);
+    }
+    if args.watch && !single_day {
+        eprintln!("--watch can only be used with a single day");
+        std::process::exit(2);
+    }
+    if args.submit && !single_day {
+        eprintln!("--submit can only be used with a single day");
+        std::process::exit(2);
+    }
+
+    let selected: Vec<&days::Day> = match &args.days {
+        Some(selection) => selection
+            .0
+            .iter()
+            .filter_map(|number| {
+                let day = days::DAYS.iter().find(|day| day.number == *number);
+                if day.is_none() {
+                    eprintln!("day{number:02} is not implemented");
+                }
+                day
+            })
+            .collect(),
+        None => days::DAYS.iter().collect(),
+    };
+
+    let mut overrides = Params::default();
+    for params in &args.params {
+        for (name, value) in params.iter() {
+            if !selected.iter().any(|day| day.params.iter().any(|param| param.name == name)) {
+                eprintln!("none of the selected days has a parameter `{name}`");
+                std::process::exit(2);
+            }
+            overrides.set(name, value);
+        }
+    }
+
+    if args.watch
+        && let [day] = selected[..]
+    {
+        let runner_args: Vec<String> = std::env::args().skip(1).filter(|arg| arg != "--watch").collect();
+        let (_, input) = input_sources(&args, day, &[]).remove(0);
+        watch::watch(day, &input, &runner_args);
+    }
+
+    let answers_path = args
+        .answers
+        .clone()
+        .unwrap_or_else(|| utils::input::workspace_root().join(answers::DEFAULT_ANSWERS_FILE));
+    let mut known_answers = KnownAnswers::load(&answers_path).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        std::process::exit(2);
+    });
+    let mut failures = 0;
+    let mut submissions = vec![];
+
+    let parts: Vec<u8> = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let sets = match &args.inputs {
+        Some(dir) => input_sets(dir).unwrap_or_else(|err| {
+            eprintln!("cannot list input sets in {}: {}", dir.display(), err);
+            std::process::exit(2);
+        }),
+        None => vec![],
+    };
+
+    let mut reporter = Reporter::new(args.format, sets.iter().map(|(name, _)| name.clone()).collect());
+    let fetcher = Fetcher::from_env();
+    // A missing input only fails the run when it was asked for, not when a day has no input yet.
+    let explicit_input = args.input.is_some() || args.inputs.is_some() || args.example;
+    let mut jobs = vec![];
+    for day in selected {
+        let params = match day.params(args.example, &overrides) {
+            Ok(params) => params,
+            Err(err) => {
+                eprintln!("{}: {}", day.name(), err);
+                std::process::exit(2);
+            }
+        };
+        let custom_params = params != Params::defaults(day.params, args.example);
+        let timed = match &args.time {
+            Some(Some(selection)) => selection.0.contains(&day.number),
+            Some(None) => true,
+            None => false,
+        };
+
+        for (input_set, source) in input_sources(&args, day, &sets) {
+            let label = match &input_set {
+                Some(name) => format!("{} ({})", day.name(), name),
+                None => day.name(),
+            };
+            let input = match source.read() {
+                Ok(input) => input,
+                // Without an explicit input, fall back to the downloaded ones.
+                Err(InputError::NotFound(_)) if args.input.is_none() && !args.example && input_set.is_none() => {
+                    match fetcher.input(day.number) {
+                        Ok(input) => input,
+                        Err(err) => {
+                            let message = format!("no input in {source} or {}: {err}", fetcher.cached_path(day.number).display());
+                            eprintln!("{label}: {message}");
+                            reporter.failure(FailedRow { day: day.number, input: input_set, error: JobError::MissingInput, message });
+                            continue;
+                        }
+                    }
+                }
+                Err(err) => {
+                    eprintln!("{label}: {err}");
+                    if explicit_input {
+                        failures += 1;
+                    }
+                    let error = match err {
+                        InputError::NotFound(_) => JobError::MissingInput,
+                        InputError::Io(..) => JobError::UnreadableInput,
+                    };
+                    reporter.failure(FailedRow { day: day.number, input: input_set, error, message: err.to_string() });
+                    continue;
+                }
+            };
+            let (input, normalization) = utils::input::normalize(&input);
+            if args.show_normalization && !normalization.is_empty() {
+                eprintln!("{label}: {normalization}");
+            }
+            jobs.push(Job {
+                day,
+                input_set,
+                label,
+                input_id: answers::input_id(&input),
+                input,
+                params: params.clone(),
+                custom_params,
+                timed,
+            });
+        }
+    }
+
+    let threads = match args.jobs {
+        Some(threads) => threads,
+        None if args.time.is_some() => 1,
+        None => 0,
+    };
+    if args.time.is_some() && threads != 1 {
+        eprintln!("warning: timing with several jobs, steps running at the same time slow each other down");
+    }
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap_or_else(|err| {
+        eprintln!("cannot start the thread pool: {err}");
+        std::process::exit(2);
+    });
+    // Progress bars would interleave with JSON consumers or end up in redirected logs.
+    let progress = (args.format == Format::Text && std::io::stderr().is_terminal()).then(Arc::<ProgressBars>::default);
+    let start = Instant::now();
+    // Days run in parallel, but results are collected in order and reported afterwards. A panicking
+    // day is caught so that it does not take the results of the others down with it.
+    let runs: Vec<std::thread::Result<Result<DayRun, ParseError>>> = pool.install(|| {
+        jobs.par_iter()
+            .map(|job| {
+                let options = RunOptions {
+                    iterations: if job.timed { args.iterations } else { 1 },
+                    progress: progress.as_ref().map(|bars| (bars, job.label.as_str())),
+                    time_limit: args.timeout,
+                };
+                std::panic::catch_unwind(AssertUnwindSafe(|| (job.day.solve)(&job.input, &job.params, &parts, &options)))
+            })
+            .collect()
+    });
+    let wall_clock = start.elapsed();
+
+    let mut total = Duration::ZERO;
+    let mut busy = Duration::ZERO;
+    let mut timed_out = 0;
+    for (job, run) in jobs.into_iter().zip(runs) {
+        let run = match run {
+            Ok(Ok(run)) => run,
+            Err(panic) => {
+                let message = panic_message(panic.as_ref());
+                eprintln!("{}: panicked: {message}", job.label);
+                failures += 1;
+                reporter.failure(FailedRow { day: job.day.number, input: job.input_set, error: JobError::Panic, message });
+                continue;
+            }
+            Ok(Err(err)) => {
+                match &job.input_set {
+                    Some(name) => eprintln!("{err} (input set {name})"),
+                    None => eprintln!("{err}"),
+                }
+                failures += 1;
+                let message = err.to_string();
+                reporter.failure(FailedRow { day: job.day.number, input: job.input_set, error: JobError::Parse, message });
+                continue;
+            }
+        };
+        busy += run.parse.total();
+        reporter.parse(job.day.number, &run.parse, run.parse_allocations, job.timed);
+        if job.timed {
+            total += run.parse.median();
+        }
+        for result in run.results {
+            // Known answers only hold for the default parameters.
+            let verification = match &result.answer {
+                None => Verification::TimedOut,
+                Some(_) if job.custom_params => Verification::Unverified,
+                Some(answer) if args.record => {
+                    known_answers.record(job.day.number, result.part, &job.input_id, answer.clone());
+                    Verification::Correct
+                }
+                Some(answer) => known_answers.verify(job.day.number, result.part, &job.input_id, answer),
+            };
+            if matches!(verification, Verification::Wrong { .. } | Verification::TimedOut) {
+                failures += 1;
+            }
+            if args.submit
+                && verification == Verification::Unverified
+                && !job.custom_params
+                && let Some(answer) = &result.answer
+            {
+                submissions.push((job.day.number, result.part, job.input_id.clone(), answer.clone()));
+            }
+            // A timed-out part only has its time limit to show, which would make the sums meaningless.
+            if result.answer.is_none() {
+                timed_out += 1;
+            } else {
+                busy += result.timings.total();
+                if job.timed {
+                    total += result.timings.median();
+                }
+            }
+            reporter.result(Row {
+                day: job.day.number,
+                part: result.part,
+                input: job.input_set.clone(),
+                answer: result.answer,
+                timings: result.timings,
+                allocations: result.allocations,
+                verification,
+                timed: job.timed,
+            });
+        }
+    }
+    reporter.finish(args.time.is_some().then_some(Summary { total, wall_clock, busy, timed_out }));
+
+    let mut accepted = false;
+    if !submissions.is_empty() {
+        let attempts_path = args
+            .attempts
+            .clone()
+            .unwrap_or_else(|| utils::input::workspace_root().join(submit::DEFAULT_ATTEMPTS_FILE));
+        let mut history = History::load(&attempts_path).unwrap_or_else(|err| {
+            eprintln!("{err}");
+            std::process::exit(2);
+        });
+        let submitter = Submitter::from_env();
+        for (day, part, input_id, answer) in submissions {
+            let label = format!("day{day:02} part {}", report::PART_NAMES[part as usize - 1]);
+            match history.check(day, part, &answer) {
+                Check::Submit => {}
+                Check::Warn(warning) => eprintln!("{label}: warning: {warning}"),
+                Check::Refuse(reason) => {
+                    eprintln!("{label}: not submitting {answer}, {reason}");
+                    continue;
+                }
+            }
+            let outcome = match submitter.submit(day, part, &answer) {
+                Ok(outcome) => outcome,
+                Err(err) => {
+                    eprintln!("{label}: cannot submit {answer}: {err}");
+                    failures += 1;
+                    continue;
+                }
+            };
+            println!("{label}: submitted {answer}: {outcome}");
+            match outcome {
+                Outcome::Correct => {
+                    known_answers.record(day, part, &input_id, answer.clone());
+                    accepted = true;
+                }
+                Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong => failures += 1,
+                _ => {}
+            }
+            if let Err(err) = history.record(&attempts_path, Attempt { day, part, outcome, answer }) {
+                eprintln!("cannot write {}: {}", attempts_path.display(), err);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    if (args.record || accepted)
+        && let Err(err) = known_answers.save(&answers_path)
+    {
+        eprintln!("cannot write {}: {}", answers_path.display(), err);
+        std::process::exit(2);
+    }
+    if failures > 0 {
+        eprintln!("{failures} failure(s): missing or unparsable inputs, panics, parts that timed out, answers that differ from the known answers or failed submissions");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use utils::progress::ProgressHook;
+
+const BAR_WIDTH: usize = 20;
+
+/// One status line on stderr with a progress bar for every part that reports progress, shared by
+/// the parts running in parallel.
+#[derive(Default)]
+pub struct ProgressBars {
+    /// Percentage done per part label.
+    active: Mutex<BTreeMap<String, usize>>,
+}
+
+impl ProgressBars {
+    /// A bar for the part `label`, shown from its first update until the guard drops.
+    pub fn bar(self: &Arc<Self>, label: String) -> BarGuard {
+        BarGuard(Arc::new(Bar { bars: self.clone(), label, finished: AtomicBool::new(false) }))
+    }
+
+    fn remove(&self, label: &str) {
+        let mut active = self.active.lock().unwrap();
+        if active.remove(label).is_some() {
+            draw(&active);
+        }
+    }
+
+    fn set(&self, bar: &Bar, percent: usize) {
+        let mut active = self.active.lock().unwrap();
+        // Checked under the lock, so an update racing with `finish` cannot bring the bar back.
+        if !bar.finished.load(Ordering::SeqCst) && active.get(&bar.label) != Some(&percent) {
+            active.insert(bar.label.clone(), percent);
+            draw(&active);
+        }
+    }
+}
+
+fn draw(active: &BTreeMap<String, usize>) {
+    let bars: Vec<String> = active
+        .iter()
+        .map(|(label, percent)| {
+            let filled = percent * BAR_WIDTH / 100;
+            format!("{label} [{}{}] {percent:3}%", "#".repeat(filled), " ".repeat(BAR_WIDTH - filled))
+        })
+        .collect();
+    let mut stderr = std::io::stderr().lock();
+    // Clear the line before redrawing, it may have held more bars.
+    let _ = write!(stderr, "\r\x1b[2K{}", bars.join(" | "));
+    let _ = stderr.flush();
+}
+
+struct Bar {
+    bars: Arc<ProgressBars>,
+    label: String,
+    /// Set once the part is over, after which updates from a part that was abandoned on timeout are
+    /// ignored.
+    finished: AtomicBool,
+}
+
+impl ProgressHook for Bar {
+    fn update(&self, done: usize, total: usize) {
+        self.bars.set(self, (done * 100).checked_div(total).unwrap_or(100));
+    }
+}
+
+/// Clears its bar when dropped, whether the part finished, timed out or panicked.
+pub struct BarGuard(Arc<Bar>);
+
+impl BarGuard {
+    pub fn hook(&self) -> Arc<dyn ProgressHook> {
+        self.0.clone()
+    }
+}
+
+impl Drop for BarGuard {
+    fn drop(&mut self) {
+        self.0.finished.store(true, Ordering::SeqCst);
+        self.0.bars.remove(&self.0.label);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finished_bars_ignore_late_updates() {
+        let bars = Arc::<ProgressBars>::default();
+        let bar = bars.bar("day09 part two".to_string());
+        let hook = bar.hook();
+        hook.update(1, 2);
+        assert_eq!(bars.active.lock().unwrap().get("day09 part two"), Some(&50));
+        drop(bar);
+        hook.update(2, 2);
+        assert!(bars.active.lock().unwrap().is_empty());
+    }
+}
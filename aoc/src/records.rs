@@ -0,0 +1,65 @@
+//! The line format shared by the known answers and the attempts log: one `day part key answer`
+//! record per line, where the answer may contain spaces, with blank lines and `#` comments ignored.
+
+use std::path::Path;
+use utils::answer::Answer;
+
+#[derive(Debug, PartialEq)]
+pub struct Record<'a> {
+    /// 1-based line number, for reporting errors about the record.
+    pub line: usize,
+    pub day: u8,
+    pub part: u8,
+    pub key: &'a str,
+    pub answer: Answer,
+}
+
+impl Record<'_> {
+    pub fn error(&self, message: impl std::fmt::Display) -> String {
+        format!("line {}: {}", self.line, message)
+    }
+}
+
+/// Loads and parses a records file; a missing file holds nothing yet.
+pub fn load<T: Default>(path: &Path, parse: impl FnOnce(&str) -> Result<T, String>) -> Result<T, String> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => parse(&content).map_err(|err| format!("{}: {}", path.display(), err)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(T::default()),
+        Err(err) => Err(format!("cannot read {}: {}", path.display(), err)),
+    }
+}
+
+/// Parses every record of `content`. `key` names the third field in errors, e.g. `input`.
+pub fn parse<'a>(content: &'a str, key: &str) -> Result<Vec<Record<'a>>, String> {
+    let mut records = vec![];
+    for (line_num, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.splitn(4, ' ');
+        let (Some(day), Some(part), Some(record_key), Some(answer)) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            return Err(format!("line {}: expected `day part {key} answer`", line_num + 1));
+        };
+        let day = day.parse().map_err(|_| format!("line {}: invalid day {day}", line_num + 1))?;
+        let part = part.parse().map_err(|_| format!("line {}: invalid part {part}", line_num + 1))?;
+        let answer = answer.parse().map_err(|err| format!("line {}: {}", line_num + 1, err))?;
+        records.push(Record { line: line_num + 1, day, part, key: record_key, answer });
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_records() {
+        let records = parse("# day part key answer\n\n7 1 ff 21\n7 2 ff ABC DEF\n", "key").unwrap();
+        assert_eq!(records[1], Record { line: 4, day: 7, part: 2, key: "ff", answer: Answer::from("ABC DEF") });
+        assert_eq!(records[0].error("oops"), "line 3: oops");
+        assert_eq!(parse("7 x ff 21", "key").unwrap_err(), "line 1: invalid part x");
+    }
+}
@@ -0,0 +1,241 @@
+use crate::allocations::AllocStats;
+use crate::answers::Verification;
+use crate::timing::Timings;
+use serde_json::json;
+use std::collections::BTreeSet;
+use std::time::Duration;
+use utils::answer::Answer;
+
+pub const PART_NAMES: [&str; 2] = ["one", "two"];
+
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+/// Overall durations: the calendar total is the sum of the median step durations, while the busy
+/// time adds up the wall-clock duration of every iteration of every step, which parallel runs fit
+/// into less wall-clock time. Parts that timed out are left out of both and only counted.
+pub struct Summary {
+    pub total: Duration,
+    pub wall_clock: Duration,
+    pub busy: Duration,
+    pub timed_out: usize,
+}
+
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    /// Name of the input set when running against a directory of inputs.
+    pub input: Option<String>,
+    /// `None` when the part timed out.
+    pub answer: Option<Answer>,
+    pub timings: Timings,
+    pub allocations: Option<AllocStats>,
+    pub verification: Verification,
+    pub timed: bool,
+}
+
+impl Row {
+    /// The answer with its verification, e.g. `40 (verified)`, or just `timed out`.
+    fn outcome(&self) -> String {
+        match &self.answer {
+            Some(answer) => format!("{answer} ({})", self.verification),
+            None => self.verification.to_string(),
+        }
+    }
+}
+
+/// Why a day produced no results for an input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JobError {
+    MissingInput,
+    UnreadableInput,
+    Parse,
+    Panic,
+}
+
+impl JobError {
+    fn status(self) -> &'static str {
+        match self {
+            JobError::MissingInput => "missing_input",
+            JobError::UnreadableInput => "unreadable_input",
+            JobError::Parse => "parse_error",
+            JobError::Panic => "panicked",
+        }
+    }
+}
+
+/// A day that failed to produce results for an input.
+pub struct FailedRow {
+    pub day: u8,
+    pub input: Option<String>,
+    pub error: JobError,
+    pub message: String,
+}
+
+/// Prints results as they come in for text output, as a day-by-input table when comparing input
+/// sets, or as one JSON array once everything ran.
+pub struct Reporter {
+    format: Format,
+    input_sets: Vec<String>,
+    rows: Vec<Row>,
+    failed: Vec<FailedRow>,
+}
+
+impl Reporter {
+    pub fn new(format: Format, input_sets: Vec<String>) -> Self {
+        Reporter { format, input_sets, rows: Vec::new(), failed: Vec::new() }
+    }
+
+    fn streaming(&self) -> bool {
+        self.format == Format::Text && self.input_sets.is_empty()
+    }
+
+    pub fn parse(&mut self, day: u8, timings: &Timings, allocations: Option<AllocStats>, timed: bool) {
+        if self.streaming() && (timed || allocations.is_some()) {
+            print!("day{day:02} parse:");
+            if timed {
+                print!(" {timings}");
+            }
+            if let Some(allocations) = allocations {
+                print!(" [{allocations}]");
+            }
+            println!();
+        }
+    }
+
+    pub fn result(&mut self, row: Row) {
+        if self.streaming() {
+            print!("day{:02} part {}: {}", row.day, PART_NAMES[row.part as usize - 1], row.outcome());
+            if row.timed {
+                print!(" [{}]", row.timings);
+            }
+            if let Some(allocations) = row.allocations {
+                print!(" [{allocations}]");
+            }
+            println!();
+        } else {
+            self.rows.push(row);
+        }
+    }
+
+    /// Records a day that failed for an input. Streamed text output already got the error on
+    /// stderr, while the table and JSON output show it in place of the day's answers.
+    pub fn failure(&mut self, row: FailedRow) {
+        if !self.streaming() {
+            self.failed.push(row);
+        }
+    }
+
+    pub fn finish(self, summary: Option<Summary>) {
+        match self.format {
+            Format::Text => {
+                if !self.input_sets.is_empty() {
+                    self.print_table();
+                }
+                if let Some(summary) = summary {
+                    print!(
+                        "total: {:.1?} (wall-clock {:.1?}, busy {:.1?})",
+                        summary.total, summary.wall_clock, summary.busy
+                    );
+                    if summary.timed_out > 0 {
+                        print!(", not counting {} part(s) that timed out", summary.timed_out);
+                    }
+                    println!();
+                }
+            }
+            Format::Json => {
+                let mut rows: Vec<(u8, serde_json::Value)> = self.rows.iter().map(|row| (row.day, json_row(row))).collect();
+                rows.extend(self.failed.iter().map(|row| (row.day, json_failed_row(row))));
+                // Inputs are read before anything runs, so failed days are put back in day order.
+                rows.sort_by_key(|(day, _)| *day);
+                println!("{}", serde_json::Value::Array(rows.into_iter().map(|(_, row)| row).collect()));
+            }
+        }
+    }
+
+    fn print_table(&self) {
+        let mut keys: BTreeSet<(u8, u8)> = self.rows.iter().map(|row| (row.day, row.part)).collect();
+        // Days that failed for every input set still get their lines, with the parts that ran elsewhere.
+        let mut parts: BTreeSet<u8> = self.rows.iter().map(|row| row.part).collect();
+        if parts.is_empty() {
+            parts = BTreeSet::from([1, 2]);
+        }
+        keys.extend(self.failed.iter().flat_map(|failed| parts.iter().map(|part| (failed.day, *part))));
+
+        let mut table = vec![];
+        let mut header = vec!["day".to_string(), "part".to_string()];
+        header.extend(self.input_sets.iter().cloned());
+        table.push(header);
+        for (day, part) in keys {
+            let mut line = vec![format!("day{day:02}"), PART_NAMES[part as usize - 1].to_string()];
+            for input_set in &self.input_sets {
+                let cell = self
+                    .rows
+                    .iter()
+                    .find(|row| (row.day, row.part) == (day, part) && row.input.as_ref() == Some(input_set))
+                    .map(|row| format!("{} {:.1?}", row.outcome(), row.timings.median()))
+                    .or_else(|| {
+                        self.failed
+                            .iter()
+                            .find(|failed| failed.day == day && failed.input.as_ref() == Some(input_set))
+                            .map(|failed| failed.error.status().replace('_', " "))
+                    })
+                    .unwrap_or_else(|| "-".to_string());
+                line.push(cell);
+            }
+            table.push(line);
+        }
+
+        let widths: Vec<usize> = (0..table[0].len())
+            .map(|column| table.iter().map(|line| line[column].chars().count()).max().unwrap_or(0))
+            .collect();
+        for line in table {
+            let cells: Vec<String> = line.iter().zip(&widths).map(|(cell, width)| format!("{cell:width$}")).collect();
+            println!("{}", cells.join("  ").trim_end());
+        }
+    }
+}
+
+fn json_row(row: &Row) -> serde_json::Value {
+    let mut value = json!({
+        "day": row.day,
+        "part": row.part,
+        "answer": row.answer.as_ref().map(Answer::to_string),
+        "duration_ns": row.timings.median().as_nanos() as u64,
+        "status": match row.verification {
+            Verification::Correct => "verified",
+            Verification::Wrong { .. } => "wrong",
+            Verification::Unverified => "unverified",
+            Verification::TimedOut => "timed_out",
+        },
+    });
+    if let Some(allocations) = row.allocations {
+        value["allocations"] = json!(allocations.allocations);
+        value["allocated_bytes"] = json!(allocations.bytes);
+        value["peak_bytes"] = json!(allocations.peak);
+    }
+    if let Some(input) = &row.input {
+        value["input"] = json!(input);
+    }
+    if let Verification::Wrong { expected } = &row.verification {
+        value["expected"] = json!(expected.to_string());
+    }
+    value
+}
+
+fn json_failed_row(row: &FailedRow) -> serde_json::Value {
+    let mut value = json!({
+        "day": row.day,
+        "part": null,
+        "answer": null,
+        "status": row.error.status(),
+        "message": row.message,
+    });
+    if let Some(input) = &row.input {
+        value["input"] = json!(input);
+    }
+    value
+}
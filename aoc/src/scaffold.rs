@@ -0,0 +1,223 @@
+//! `aoc new N`: creates the `dayNN` crate from a template and registers it in the workspace, the
+//! runner's dependencies and the days registry.
+
+use std::path::Path;
+
+/// Crates a new day can start with besides `utils`, at the versions the other days use.
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum Dependency {
+    Petgraph,
+    Itertools,
+    Geo,
+}
+
+impl Dependency {
+    fn requirement(self) -> &'static str {
+        match self {
+            Dependency::Petgraph => "petgraph = \"0.8.3\"",
+            Dependency::Itertools => "itertools = \"0.14.0\"",
+            Dependency::Geo => "geo = \"0.32.0\"",
+        }
+    }
+}
+
+pub fn new_day(number: u8, dependencies: &[Dependency]) -> Result<(), String> {
+    let root = utils::input::workspace_root();
+    let name = format!("day{number:02}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    // Check that every registry can be edited before touching anything.
+    let workspace_manifest = edit(&root.join("Cargo.toml"), |content| add_member(content, number))?;
+    let runner_manifest = edit(&root.join("aoc").join("Cargo.toml"), |content| add_dependency(content, number))?;
+    let registry = edit(&root.join("aoc").join("src").join("days.rs"), |content| register_day(content, number))?;
+
+    let src = dir.join("src");
+    std::fs::create_dir_all(&src).map_err(|err| format!("cannot create {}: {}", src.display(), err))?;
+    let mut files = vec![
+        (dir.join("Cargo.toml"), cargo_toml(&name, dependencies)),
+        (src.join("lib.rs"), lib_rs(number)),
+        (src.join("main.rs"), main_rs(number)),
+        (src.join("example.txt"), String::new()),
+        (src.join(utils::examples::MANIFEST_FILE), "# file            part  answer  params\n".to_string()),
+    ];
+    files.extend([workspace_manifest, runner_manifest, registry]);
+    for (path, content) in files {
+        std::fs::write(&path, content).map_err(|err| format!("cannot write {}: {}", path.display(), err))?;
+    }
+    Ok(())
+}
+
+fn edit(
+    path: &Path,
+    change: impl FnOnce(&str) -> Result<String, String>,
+) -> Result<(std::path::PathBuf, String), String> {
+    let content = std::fs::read_to_string(path).map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+    let changed = change(&content).map_err(|err| format!("{}: {}", path.display(), err))?;
+    Ok((path.to_path_buf(), changed))
+}
+
+/// The number in a day name at the start of `text`, e.g. 7 for `day07", "day08"]`.
+fn day_number(text: &str) -> Option<u8> {
+    let digits = text.strip_prefix("day")?;
+    let end = digits.find(|c: char| !c.is_ascii_digit()).unwrap_or(digits.len());
+    digits[..end].parse().ok()
+}
+
+/// Where `number` goes among `days`, the positions and numbers of the existing days: before the
+/// first later day, or after the last one.
+fn position(days: &[(usize, u8)], number: u8) -> Option<Result<usize, usize>> {
+    match days.iter().find(|(_, day)| *day > number) {
+        Some((at, _)) => Some(Ok(*at)),
+        None => days.last().map(|(at, _)| Err(*at)),
+    }
+}
+
+/// Adds the day to the workspace `members`, in day order.
+fn add_member(manifest: &str, number: u8) -> Result<String, String> {
+    let line = manifest
+        .lines()
+        .find(|line| line.trim_start().starts_with("members"))
+        .ok_or("no workspace members")?;
+    let days: Vec<(usize, u8)> = line
+        .match_indices("\"day")
+        .filter_map(|(at, _)| Some((at, day_number(&line[at + 1..])?)))
+        .collect();
+    let new_line = match position(&days, number).ok_or("no day among the workspace members")? {
+        Ok(before) => format!("{}\"day{number:02}\", {}", &line[..before], &line[before..]),
+        Err(last) => {
+            let after = last + line[last + 1..].find('"').ok_or("unterminated member")? + 2;
+            format!("{}, \"day{number:02}\"{}", &line[..after], &line[after..])
+        }
+    };
+    Ok(manifest.replacen(line, &new_line, 1))
+}
+
+/// Adds a path dependency on the day to the runner, in day order.
+fn add_dependency(manifest: &str, number: u8) -> Result<String, String> {
+    let mut lines: Vec<String> = manifest.lines().map(str::to_string).collect();
+    let days: Vec<(usize, u8)> =
+        lines.iter().enumerate().filter_map(|(index, line)| Some((index, day_number(line)?))).collect();
+    let index = match position(&days, number).ok_or("no day among the dependencies")? {
+        Ok(before) => before,
+        Err(last) => last + 1,
+    };
+    lines.insert(index, format!("day{number:02} = {{ path = \"../day{number:02}\" }}"));
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day to `DAYS`, in day order, and bumps its length.
+fn register_day(days_rs: &str, number: u8) -> Result<String, String> {
+    const DECLARATION: &str = "pub const DAYS: [Day; ";
+    const ENTRY: &str = "\n    day::<";
+    let start = days_rs.find(DECLARATION).ok_or("no DAYS registry")? + DECLARATION.len();
+    let length_end = start + days_rs[start..].find(']').ok_or("no DAYS length")?;
+    let length: usize = days_rs[start..length_end].parse().map_err(|_| "invalid DAYS length")?;
+    let end = length_end + days_rs[length_end..].find("\n];").ok_or("unterminated DAYS registry")?;
+    let entries = &days_rs[length_end..end];
+    let days: Vec<(usize, u8)> = entries
+        .match_indices(ENTRY)
+        .filter_map(|(at, _)| Some((at, day_number(&entries[at + ENTRY.len()..])?)))
+        .collect();
+    let insert_at = match position(&days, number) {
+        Some(Ok(before)) => before,
+        _ => entries.len(),
+    };
+    Ok(format!(
+        "{}{}{}{ENTRY}day{number:02}::Day{number:02}>(),{}{}",
+        &days_rs[..start],
+        length + 1,
+        &entries[..insert_at],
+        &entries[insert_at..],
+        &days_rs[end..]
+    ))
+}
+
+fn cargo_toml(name: &str, dependencies: &[Dependency]) -> String {
+    let mut toml = format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n[dependencies]\n");
+    for dependency in dependencies {
+        toml.push_str(dependency.requirement());
+        toml.push('\n');
+    }
+    toml.push_str("utils = { path = \"../utils\"}\n");
+    toml
+}
+
+fn lib_rs(number: u8) -> String {
+    format!(
+        r#"use utils::parse::ParseError;
+use utils::params::Params;
+use utils::solution::Solution;
+
+pub struct Day{number:02};
+
+impl Solution for Day{number:02} {{
+    const DAY: u8 = {number};
+
+    type Input = String;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str, _params: &Params) -> Result<Self::Input, ParseError> {{
+        Ok(input.to_string())
+    }}
+
+    fn part_one(_input: &Self::Input) -> u64 {{
+        0
+    }}
+
+    fn part_two(_input: &Self::Input) -> u64 {{
+        0
+    }}
+}}
+
+utils::example_tests!(Day{number:02});
+"#
+    )
+}
+
+fn main_rs(number: u8) -> String {
+    format!("fn main() {{\n    utils::solution::run::<day{number:02}::Day{number:02}>();\n}}\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_the_day() {
+        assert_eq!(
+            add_member("[workspace]\n\nmembers = [\"day01\", \"day02\", \"utils\", \"aoc\"]\n", 3).unwrap(),
+            "[workspace]\n\nmembers = [\"day01\", \"day02\", \"day03\", \"utils\", \"aoc\"]\n"
+        );
+        assert_eq!(
+            add_dependency("[dependencies]\nutils = { path = \"../utils\" }\nday01 = { path = \"../day01\" }\n", 2)
+                .unwrap(),
+            "[dependencies]\nutils = { path = \"../utils\" }\nday01 = { path = \"../day01\" }\nday02 = { path = \"../day02\" }\n"
+        );
+        assert_eq!(
+            register_day("pub const DAYS: [Day; 1] = [\n    day::<day01::Day01>(),\n];\n", 2).unwrap(),
+            "pub const DAYS: [Day; 2] = [\n    day::<day01::Day01>(),\n    day::<day02::Day02>(),\n];\n"
+        );
+        assert!(register_day("fn main() {}", 2).is_err());
+    }
+
+    #[test]
+    fn registers_an_earlier_day_in_order() {
+        assert_eq!(
+            add_member("members = [\"day12\", \"day20\", \"utils\"]\n", 15).unwrap(),
+            "members = [\"day12\", \"day15\", \"day20\", \"utils\"]\n"
+        );
+        assert_eq!(
+            add_dependency("utils = { path = \"../utils\" }\nday20 = { path = \"../day20\" }\n", 15).unwrap(),
+            "utils = { path = \"../utils\" }\nday15 = { path = \"../day15\" }\nday20 = { path = \"../day20\" }\n"
+        );
+        assert_eq!(
+            register_day("pub const DAYS: [Day; 2] = [\n    day::<day12::Day12>(),\n    day::<day20::Day20>(),\n];\n", 15)
+                .unwrap(),
+            "pub const DAYS: [Day; 3] = [\n    day::<day12::Day12>(),\n    day::<day15::Day15>(),\n    day::<day20::Day20>(),\n];\n"
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+/// A sorted set of day numbers, parsed from a selection like `7`, `3-5` or `1,4,10-12`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DaySelection(pub Vec<u8>);
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_days(input).map(DaySelection)
+    }
+}
+
+fn parse_days(input: &str) -> Result<Vec<u8>, String> {
+    let mut days = BTreeSet::new();
+    for part in input.split(',') {
+        let (low, high) = match part.split_once('-') {
+            Some((low, high)) => (parse_day(low)?, parse_day(high)?),
+            None => {
+                let day = parse_day(part)?;
+                (day, day)
+            }
+        };
+        if low > high {
+            return Err(format!("invalid day range: {part}"));
+        }
+        days.extend(low..=high);
+    }
+    Ok(days.into_iter().collect())
+}
+
+fn parse_day(input: &str) -> Result<u8, String> {
+    match input.trim().parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day: {input}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_day() {
+        assert_eq!(parse_days("7"), Ok(vec![7]));
+    }
+
+    #[test]
+    fn ranges_and_lists() {
+        assert_eq!(parse_days("10-12,1,4"), Ok(vec![1, 4, 10, 11, 12]));
+    }
+
+    #[test]
+    fn invalid_days() {
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("5-3").is_err());
+        assert!(parse_days("x").is_err());
+    }
+}
@@ -0,0 +1,222 @@
+//! Submits answers to the puzzle server and keeps a log of every attempt, so that answers already
+//! known to be wrong are never sent again.
+
+use crate::fetch::{self, FetchError, HttpClient, UreqClient};
+use crate::records;
+use std::fmt;
+use std::io::Write;
+use std::path::Path;
+use utils::answer::Answer;
+
+pub const DEFAULT_ATTEMPTS_FILE: &str = "attempts.txt";
+
+/// The server's verdict on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited,
+    /// The part was solved before, so the server does not check answers anymore.
+    AlreadySolved,
+    Unrecognized,
+}
+
+const OUTCOME_NAMES: [(Outcome, &str); 7] = [
+    (Outcome::Correct, "correct"),
+    (Outcome::TooHigh, "too-high"),
+    (Outcome::TooLow, "too-low"),
+    (Outcome::Wrong, "wrong"),
+    (Outcome::RateLimited, "rate-limited"),
+    (Outcome::AlreadySolved, "already-solved"),
+    (Outcome::Unrecognized, "unrecognized"),
+];
+
+impl Outcome {
+    /// Reads the verdict out of the answer page the server responds with.
+    pub fn from_response(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if page.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if page.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else if page.contains("You gave an answer too recently") {
+            Outcome::RateLimited
+        } else if page.contains("Did you already complete it?") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unrecognized
+        }
+    }
+
+    fn name(self) -> &'static str {
+        OUTCOME_NAMES.iter().find(|(outcome, _)| *outcome == self).map(|(_, name)| *name).unwrap()
+    }
+
+    fn is_wrong(self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::RateLimited => write!(f, "rate-limited, try again later"),
+            Outcome::Unrecognized => write!(f, "unrecognized response"),
+            outcome => write!(f, "{}", outcome.name().replace('-', " ")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub answer: Answer,
+}
+
+/// What the attempt history says about submitting an answer.
+#[derive(Debug, PartialEq)]
+pub enum Check {
+    Submit,
+    /// Worth submitting, but the answer is outside the bounds of earlier attempts.
+    Warn(String),
+    Refuse(String),
+}
+
+/// Every attempted answer with the server's verdict, stored as `day part outcome answer` lines.
+#[derive(Debug, Default)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Loads the attempts file; a missing file means nothing was submitted yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        records::load(path, Self::parse)
+    }
+
+    fn parse(content: &str) -> Result<Self, String> {
+        let mut attempts = vec![];
+        for record in records::parse(content, "outcome")? {
+            let outcome = OUTCOME_NAMES
+                .iter()
+                .find(|(_, name)| *name == record.key)
+                .map(|(outcome, _)| *outcome)
+                .ok_or_else(|| record.error(format!("invalid outcome {}", record.key)))?;
+            attempts.push(Attempt { day: record.day, part: record.part, outcome, answer: record.answer });
+        }
+        Ok(History { attempts })
+    }
+
+    /// Adds `attempt` to the history and appends it to the attempts file.
+    pub fn record(&mut self, path: &Path, attempt: Attempt) -> std::io::Result<()> {
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+        if file.metadata()?.len() == 0 {
+            writeln!(file, "# day part outcome answer")?;
+        }
+        writeln!(file, "{} {} {} {}", attempt.day, attempt.part, attempt.outcome.name(), attempt.answer)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Check {
+        let attempts: Vec<&Attempt> =
+            self.attempts.iter().filter(|attempt| (attempt.day, attempt.part) == (day, part)).collect();
+        if let Some(solved) = attempts.iter().find(|attempt| attempt.outcome == Outcome::Correct) {
+            return Check::Refuse(format!("already solved with {}", solved.answer));
+        }
+        if let Some(rejected) = attempts.iter().find(|attempt| attempt.outcome.is_wrong() && attempt.answer == *answer) {
+            return Check::Refuse(format!("{answer} was already rejected as {}", rejected.outcome));
+        }
+
+        let Answer::Number(value) = answer else {
+            return Check::Submit;
+        };
+        let numbers = |outcome: Outcome| {
+            attempts.iter().filter(move |attempt| attempt.outcome == outcome).filter_map(|attempt| match attempt.answer {
+                Answer::Number(number) => Some(number),
+                Answer::Text(_) => None,
+            })
+        };
+        match (numbers(Outcome::TooHigh).min(), numbers(Outcome::TooLow).max()) {
+            (Some(bound), _) if *value >= bound => Check::Warn(format!("{value} is not below {bound}, which was too high")),
+            (_, Some(bound)) if *value <= bound => Check::Warn(format!("{value} is not above {bound}, which was too low")),
+            _ => Check::Submit,
+        }
+    }
+}
+
+pub struct Submitter<C> {
+    client: C,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Submitter<UreqClient> {
+    pub fn from_env() -> Self {
+        Submitter::new(UreqClient::default(), fetch::BASE_URL, fetch::session_from_env())
+    }
+}
+
+impl<C: HttpClient> Submitter<C> {
+    pub fn new(client: C, base_url: &str, session: Option<String>) -> Self {
+        Submitter { client, base_url: base_url.trim_end_matches('/').to_string(), session }
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &Answer) -> Result<Outcome, FetchError> {
+        let session = self.session.as_deref().ok_or(FetchError::NoSession)?;
+        let url = format!("{}/{}/day/{day}/answer", self.base_url, fetch::YEAR);
+        let page = self.client.post(&url, session, &[("level", &part.to_string()), ("answer", &answer.to_string())])?;
+        Ok(Outcome::from_response(&page))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::{serve, temp_dir};
+
+    fn attempt(outcome: Outcome, answer: u64) -> Attempt {
+        Attempt { day: 1, part: 2, outcome, answer: Answer::from(answer) }
+    }
+
+    #[test]
+    fn history_round_trip() {
+        let dir = temp_dir("attempts");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(DEFAULT_ATTEMPTS_FILE);
+        let mut history = History::load(&path).unwrap();
+        history.record(&path, attempt(Outcome::TooHigh, 7000)).unwrap();
+        history.record(&path, attempt(Outcome::RateLimited, 6000)).unwrap();
+        assert_eq!(History::load(&path).unwrap().attempts, history.attempts);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn checks_against_earlier_attempts() {
+        let history = History { attempts: vec![attempt(Outcome::TooHigh, 7000), attempt(Outcome::TooLow, 5000)] };
+        assert_eq!(history.check(1, 2, &Answer::from(6000u64)), Check::Submit);
+        assert_eq!(history.check(1, 1, &Answer::from(7000u64)), Check::Submit);
+        assert_eq!(history.check(1, 2, &Answer::from(7000u64)), Check::Refuse("7000 was already rejected as too high".into()));
+        assert_eq!(history.check(1, 2, &Answer::from(8000u64)), Check::Warn("8000 is not below 7000, which was too high".into()));
+        assert_eq!(history.check(1, 2, &Answer::from(10u64)), Check::Warn("10 is not above 5000, which was too low".into()));
+
+        let solved = History { attempts: vec![attempt(Outcome::Correct, 6123)] };
+        assert_eq!(solved.check(1, 2, &Answer::from(6000u64)), Check::Refuse("already solved with 6123".into()));
+    }
+
+    #[test]
+    fn submits_to_the_server() {
+        let (url, requests) = serve("200 OK", "<article><p>That's not the right answer; your answer is too low.</p></article>");
+        let submitter = Submitter::new(UreqClient::default(), &url, Some("secret".to_string()));
+        assert_eq!(submitter.submit(3, 2, &Answer::from(42u64)).unwrap(), Outcome::TooLow);
+        assert_eq!(*requests.lock().unwrap(), ["post /2025/day/3/answer http/1.1\ncookie: session=secret\nlevel=2&answer=42"]);
+        assert!(matches!(Submitter::new(UreqClient::default(), &url, None).submit(3, 2, &Answer::from(1u64)), Err(FetchError::NoSession)));
+    }
+}
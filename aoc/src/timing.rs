@@ -0,0 +1,79 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Durations of repeated runs of the same step.
+#[derive(Debug, Clone, Default)]
+pub struct Timings {
+    samples: Vec<Duration>,
+}
+
+impl Timings {
+    pub fn single(duration: Duration) -> Self {
+        Timings { samples: vec![duration] }
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples.iter().min().copied().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut sorted = self.samples.clone();
+        sorted.sort_unstable();
+        sorted.get(sorted.len() / 2).copied().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples.iter().max().copied().unwrap_or_default()
+    }
+
+    pub fn total(&self) -> Duration {
+        self.samples.iter().sum()
+    }
+}
+
+impl fmt::Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.samples.len() == 1 {
+            write!(f, "{:.1?}", self.samples[0])
+        } else {
+            write!(f, "min {:.1?} / median {:.1?} / max {:.1?}", self.min(), self.median(), self.max())
+        }
+    }
+}
+
+/// Runs `step` `iterations` times (at least once) and returns the last result with all durations.
+pub fn measure<T>(iterations: usize, mut step: impl FnMut() -> T) -> (T, Timings) {
+    let mut samples = Vec::with_capacity(iterations);
+    loop {
+        let start = Instant::now();
+        let result = step();
+        samples.push(start.elapsed());
+        if samples.len() >= iterations {
+            return (result, Timings { samples });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statistics() {
+        let timings = Timings { samples: [5, 1, 3].map(Duration::from_millis).to_vec() };
+        assert_eq!(timings.min(), Duration::from_millis(1));
+        assert_eq!(timings.median(), Duration::from_millis(3));
+        assert_eq!(timings.max(), Duration::from_millis(5));
+    }
+
+    #[test]
+    fn measure_runs_at_least_once() {
+        let mut runs = 0;
+        let (result, timings) = measure(0, || {
+            runs += 1;
+            runs
+        });
+        assert_eq!((result, timings.samples.len()), (1, 1));
+        assert_eq!(measure(3, || ()).1.samples.len(), 3);
+    }
+}
@@ -0,0 +1,153 @@
+//! `aoc N --watch`: re-runs a day's tests and parts whenever its sources or input change. The
+//! runner links the days in, so every round goes through cargo to pick up the edited code.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime};
+use utils::input::InputSource;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Editors often write a file in several steps, so a change only counts once things settle.
+const SETTLE_DELAY: Duration = Duration::from_millis(100);
+
+/// Answer and status per part, e.g. `40` and `verified`.
+type Answers = BTreeMap<u8, (String, String)>;
+
+/// Watches `day` forever, forwarding `runner_args` to each run of the runner.
+pub fn watch(day: &crate::days::Day, input: &InputSource, runner_args: &[String]) -> ! {
+    let root = utils::input::workspace_root();
+    let mut watched = vec![root.join(day.name()).join("src"), root.join(day.name()).join("Cargo.toml")];
+    if let InputSource::File(path) = input {
+        watched.push(path.clone());
+    }
+
+    let mut previous = Answers::new();
+    let mut files = snapshot(&watched);
+    loop {
+        eprintln!("--- {} ---", day.name());
+        run_tests(&root, day);
+        match run_day(&root, day, runner_args) {
+            Some(answers) => {
+                for line in diff(&previous, &answers) {
+                    println!("{} {line}", day.name());
+                }
+                previous = answers;
+            }
+            None => eprintln!("{}: no answers, see the errors above", day.name()),
+        }
+
+        eprintln!("watching {} for changes...", day.name());
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+            let current = snapshot(&watched);
+            if current != files {
+                std::thread::sleep(SETTLE_DELAY);
+                files = snapshot(&watched);
+                break;
+            }
+        }
+    }
+}
+
+/// Runs `cargo <subcommand>` on the workspace from the caller's directory, where relative paths
+/// among the runner arguments, such as `--input`, point.
+fn cargo(root: &Path, subcommand: &str) -> Command {
+    let mut command = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.arg(subcommand).arg("--manifest-path").arg(root.join("Cargo.toml"));
+    command
+}
+
+/// Runs the day's tests, only showing their output when they fail.
+fn run_tests(root: &Path, day: &crate::days::Day) {
+    match cargo(root, "test").args(["-q", "-p", &day.name()]).output() {
+        Ok(output) if output.status.success() => eprintln!("{}: tests pass", day.name()),
+        Ok(output) => {
+            eprint!("{}{}", String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+            eprintln!("{}: tests FAILED", day.name());
+        }
+        Err(err) => eprintln!("cannot run cargo test: {err}"),
+    }
+}
+
+/// Runs the day through a freshly built runner and collects its answers by part.
+fn run_day(root: &Path, day: &crate::days::Day, runner_args: &[String]) -> Option<Answers> {
+    let mut command = cargo(root, "run");
+    command.args(["-q", "-p", "aoc"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    let output = command
+        .arg("--")
+        .args(runner_args)
+        .args(["--format", "json"])
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|err| eprintln!("cannot run cargo run: {err}"))
+        .ok()?;
+    let rows: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).ok()?;
+    let answers: Answers = rows
+        .iter()
+        .filter(|row| row["day"] == day.number)
+        .filter_map(|row| {
+            let answer = row["answer"].as_str().unwrap_or("-").to_string();
+            let status = row["status"].as_str()?.replace('_', " ");
+            Some((row["part"].as_u64()? as u8, (answer, status)))
+        })
+        .collect();
+    (!answers.is_empty()).then_some(answers)
+}
+
+/// One line per part: the answer and whether it changed since the previous run.
+fn diff(previous: &Answers, current: &Answers) -> Vec<String> {
+    current
+        .iter()
+        .map(|(part, (answer, status))| {
+            let name = crate::report::PART_NAMES[*part as usize - 1];
+            match previous.get(part) {
+                Some((old, _)) if old != answer => format!("part {name}: {answer} ({status}, was {old})"),
+                Some(_) => format!("part {name}: {answer} ({status}, unchanged)"),
+                None => format!("part {name}: {answer} ({status})"),
+            }
+        })
+        .collect()
+}
+
+/// Modification times of the files under `paths`, missing ones left out.
+fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    fn visit(path: &Path, files: &mut BTreeMap<PathBuf, SystemTime>) {
+        let Ok(metadata) = std::fs::metadata(path) else {
+            return;
+        };
+        if metadata.is_dir() {
+            for entry in std::fs::read_dir(path).into_iter().flatten().flatten() {
+                visit(&entry.path(), files);
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            files.insert(path.to_path_buf(), modified);
+        }
+    }
+
+    let mut files = BTreeMap::new();
+    for path in paths {
+        visit(path, &mut files);
+    }
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_against_previous_answers() {
+        let answers = |two: &str| {
+            Answers::from([(1, ("40".to_string(), "verified".to_string())), (2, (two.to_string(), "unverified".to_string()))])
+        };
+        assert_eq!(diff(&Answers::new(), &answers("25272"))[0], "part one: 40 (verified)");
+        assert_eq!(
+            diff(&answers("25000"), &answers("25272")),
+            ["part one: 40 (verified, unchanged)", "part two: 25272 (unverified, was 25000)"]
+        );
+    }
+}
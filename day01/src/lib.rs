@@ -0,0 +1,90 @@
+use utils::parse::{parse_field, ParseError};
+use utils::params::Params;
+use utils::solution::Solution;
+
+fn parse_input(input: &str) -> Result<Vec<(i32, u32)>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_num, line)| {
+            let Some((direction_str, amount_str)) = line.split_at_checked(1) else {
+                return Err(ParseError::new(line_num, line, line, "expected a rotation like `L68`"));
+            };
+            let direction = match direction_str {
+                "L" => -1i32,
+                "R" => 1i32,
+                _ => return Err(ParseError::new(line_num, line, direction_str, "expected `L` or `R`")),
+            };
+            let amount = parse_field::<u32>(line_num, line, amount_str)?;
+            Ok((direction, amount))
+        })
+        .collect()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<(i32, u32)>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(inputs: &Self::Input) -> u32 {
+        let mut pos = 50i32;
+        let mut result = 0u32;
+        for input in inputs {
+            pos += input.0 * input.1 as i32;
+            pos = pos.rem_euclid(100);
+            if pos == 0 {
+                result += 1;
+            }
+        }
+        result
+    }
+
+    fn part_two(inputs: &Self::Input) -> u32 {
+        let cancel = utils::cancel::current();
+        let mut pos = 50i32;
+        let mut result = 0u32;
+        for input in inputs {
+            if cancel.is_cancelled() {
+                break;
+            }
+            for _ in 0..input.1 {
+                pos += input.0;
+                match pos {
+                    0 => result += 1,
+                    -1 => {
+                        pos = 99;
+                    }
+                    100 => {
+                        pos = 0;
+                        result += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        result
+    }
+}
+
+utils::example_tests!(Day01);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_input() {
+        let err = Day01::parse("L68\nX30\n", &Params::default()).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "X"));
+        let err = Day01::parse("L68\nR\n", &Params::default()).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, ""));
+    }
+}
@@ -0,0 +1,75 @@
+use utils::parse::{parse_field, split_field, ParseError};
+use utils::params::Params;
+use utils::solution::Solution;
+
+fn parse_ranges(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    let mut ranges = Vec::new();
+    for (line_num, line) in input.lines().enumerate() {
+        for range in line.split(',') {
+            let (low_str, high_str) = split_field(line_num, line, range, '-')?;
+            ranges.push((parse_field(line_num, line, low_str)?, parse_field(line_num, line, high_str)?));
+        }
+    }
+    Ok(ranges)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(u64, u64)>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+        parse_ranges(input)
+    }
+
+    fn part_one(ranges: &Self::Input) -> u64 {
+        let cancel = utils::cancel::current();
+        let mut result = 0;
+        for range in ranges {
+            for i in range.0..=range.1 {
+                if cancel.is_cancelled() {
+                    return result;
+                }
+                let i_str = i.to_string();
+                let len = i_str.len();
+                if len % 2 == 1 {
+                    continue;
+                }
+                let first = &i_str[len/2..];
+                let second = &i_str[..len/2];
+                if first == second {
+                    result += i;
+                }
+            }
+        }
+        result
+    }
+
+    fn part_two(ranges: &Self::Input) -> u64 {
+        let cancel = utils::cancel::current();
+        let mut result = 0;
+        for range in ranges {
+            for i in range.0..=range.1 {
+                if cancel.is_cancelled() {
+                    return result;
+                }
+                let i_str = i.to_string();
+                let len = i_str.len();
+                for chunk_len in 1..=len/2 {
+                    let chunk = &i_str[..chunk_len];
+                    if chunk.repeat(len/chunk_len) == i_str {
+                        result += i;
+                        break;
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+utils::example_tests!(Day02);
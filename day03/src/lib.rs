@@ -0,0 +1,83 @@
+use std::cmp::Ordering;
+use utils::parse::ParseError;
+use utils::params::Params;
+use utils::solution::Solution;
+
+/// Part two turns on this many batteries, so every bank needs at least as many.
+const BATTERIES: usize = 12;
+
+fn parse_banks(input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_num, line)| {
+            let bank: Vec<u8> = line.char_indices().map(|(i, c)| {
+                c.to_digit(10)
+                    .map(|digit| digit as u8)
+                    .ok_or_else(|| ParseError::new(line_num, line, &line[i..i + c.len_utf8()], "expected a digit"))
+            }).collect::<Result<_, _>>()?;
+            if bank.len() < BATTERIES {
+                return Err(ParseError::new(line_num, line, line, format!("expected at least {BATTERIES} batteries")));
+            }
+            Ok(bank)
+        })
+        .collect()
+}
+
+fn get_max_digit(bank: &[u8], index_from: usize, index_up_to: usize) -> (usize, u8) {
+    bank[index_from..index_up_to].iter().enumerate().max_by(|(ai, ad), (bi, bd)| {
+        match ad.cmp(bd) {
+            Ordering::Less => { Ordering::Less}
+            Ordering::Equal => { bi.cmp(ai) }
+            Ordering::Greater => { Ordering::Greater}
+        }
+    }).map(|(index, val)| (index + index_from, *val)).unwrap()
+}
+
+fn get_max_joltage(bank: &[u8], number_of_batteries: usize) -> u64 {
+    let mut digits = Vec::new();
+    let mut next_digit_start_index = 0;
+    for battery_i in 0..number_of_batteries {
+        let (digit_loc, digit_val) = get_max_digit(bank, next_digit_start_index, bank.len() - (number_of_batteries - battery_i - 1));
+        next_digit_start_index = digit_loc + 1;
+        digits.push(digit_val);
+    }
+    digits.iter().fold(0u64, |acc, digit| acc*10 + *digit as u64)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<u8>>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+        parse_banks(input)
+    }
+
+    fn part_one(banks: &Self::Input) -> u64 {
+        banks.iter().fold(0u64, |acc, bank| acc + get_max_joltage(bank, 2))
+    }
+
+    fn part_two(banks: &Self::Input) -> u64 {
+        banks.iter().fold(0u64, |acc, bank| acc + get_max_joltage(bank, BATTERIES))
+    }
+}
+
+utils::example_tests!(Day03);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_input() {
+        let err = Day03::parse("987654321111111\n8118x1111111119\n", &Params::default()).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 5, "x"));
+        let err = Day03::parse("987654321111111\n12345\n", &Params::default()).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "12345"));
+    }
+}
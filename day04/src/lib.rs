@@ -0,0 +1,63 @@
+use utils::coord::Coord;
+use utils::matrix;
+use utils::parse::ParseError;
+use utils::params::Params;
+use utils::solution::Solution;
+
+fn count_neighbours(matrix: &[Vec<char>], coord: Coord<usize>) -> usize {
+    coord.neighbours().filter(|neighbour| matrix::get(matrix, *neighbour) == Some(&'@')).count()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Vec<char>>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+        Ok(utils::matrix::parse_matrix(input))
+    }
+
+    fn part_one(paper_map: &Self::Input) -> i32 {
+        let mut full_sum = 0;
+        for (y, row) in paper_map.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                if *cell == '@' && count_neighbours(paper_map, Coord::new(x, y)) < 4 {
+                    full_sum += 1;
+                }
+            }
+        }
+
+        full_sum
+    }
+
+    fn part_two(paper_map: &Self::Input) -> i32 {
+        let mut paper_map = paper_map.clone();
+        let mut full_sum = 0;
+        let mut started = true;
+        let mut removed: Vec<Coord<usize>> = Vec::new();
+        while !removed.is_empty() || started {
+            started = false;
+            for coord in removed.iter() {
+                paper_map[coord.y][coord.x] = '.';
+            }
+            removed.clear();
+            for (y, row) in paper_map.iter().enumerate() {
+                for (x, cell) in row.iter().enumerate() {
+                    if *cell == '@' && count_neighbours(&paper_map, Coord::new(x, y)) < 4 {
+                        full_sum += 1;
+                        removed.push(Coord::new(x, y));
+                    }
+                }
+            }
+        }
+
+
+        full_sum
+    }
+}
+
+utils::example_tests!(Day04);
@@ -0,0 +1,100 @@
+use utils::parse::{parse_field, split_field, ParseError};
+use utils::params::Params;
+use utils::solution::Solution;
+
+#[derive(Debug, Clone)]
+pub struct FreshIdRange {
+    start: u64,
+    end: u64,
+}
+
+impl FreshIdRange {
+    fn from_line(line_num: usize, line: &str) -> Result<Self, ParseError> {
+        let (start, end) = split_field(line_num, line, line, '-')?;
+        Ok(Self {
+            start: parse_field(line_num, line, start)?,
+            end: parse_field(line_num, line, end)?,
+        })
+    }
+
+    fn contains(&self, id: u64) -> bool {
+        self.start <= id && id <= self.end
+    }
+}
+
+fn parse(input: &str) -> Result<(Vec<FreshIdRange>, Vec<u64>), ParseError> {
+    let mut lines = input.lines().enumerate();
+    let mut fresh_ranges = Vec::new();
+    let mut found_separator = false;
+    for (line_num, line) in lines.by_ref() {
+        if line.is_empty() {
+            found_separator = true;
+            break;
+        }
+        fresh_ranges.push(FreshIdRange::from_line(line_num, line)?);
+    }
+    if !found_separator {
+        return Err(ParseError::at_end(input, "expected a blank line between the fresh ID ranges and the available IDs"));
+    }
+    let ids = lines.map(|(line_num, line)| parse_field(line_num, line, line)).collect::<Result<_, _>>()?;
+    Ok((fresh_ranges, ids))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Vec<FreshIdRange>, Vec<u64>);
+    type PartOne = u32;
+    type PartTwo = u64;
+
+    fn parse(input: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one((fresh_ranges, ids): &Self::Input) -> u32 {
+        let mut result = 0u32;
+        for id in ids.iter() {
+            if fresh_ranges.iter().any(|range| range.contains(*id)) {
+                result += 1;
+                continue;
+            }
+        }
+        result
+    }
+
+    fn part_two((fresh_ranges, _ids): &Self::Input) -> u64 {
+        let mut fresh_ranges = fresh_ranges.clone();
+        fresh_ranges.sort_unstable_by_key(|range| range.start);
+
+        struct Acc {
+            count: u64,
+            last_end: u64
+        }
+        fresh_ranges.iter().fold(Acc{ count:0, last_end:0}, |acc: Acc, range| {
+            let start = range.start.max(acc.last_end);
+            let end = range.end + 1;
+            if start < end {
+                Acc{ count: acc.count +(end-start), last_end: end}
+            } else {
+                acc
+            }
+        }).count
+    }
+}
+
+utils::example_tests!(Day05);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_input() {
+        let err = Day05::parse("3-5\n10-1x\n\n1\n", &Params::default()).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "1x"));
+        let err = Day05::parse("3-5\n10-14\n", &Params::default()).unwrap_err();
+        assert_eq!(err.line, 3);
+    }
+}
@@ -0,0 +1,119 @@
+use utils::parse::{parse_field, ParseError};
+use utils::params::Params;
+use utils::solution::Solution;
+
+#[derive(Debug)]
+pub struct Equation {
+    pub operands: Vec<u64>,
+    pub operator: char
+}
+
+fn parse_one(input: &str) -> Result<Vec<Equation>, ParseError> {
+    let mut equations : Vec<Equation> = Vec::new();
+
+    for (line_num, line) in input.lines().enumerate() {
+        let splits = line.split_ascii_whitespace().collect::<Vec<&str>>();
+        for (i, split) in splits.iter().enumerate() {
+            if equations.len() <= i { equations.push(Equation{operands: Vec::new(), operator: ' '}); }
+            let equation: &mut Equation = equations.get_mut(i).unwrap();
+            match split {
+                &"+" => { equation.operator = '+' }
+                &"*" => { equation.operator = '*' }
+                a => { equation.operands.push(parse_field(line_num, line, a)?); }
+            }
+        }
+    }
+    if let Some(i) = equations.iter().position(|equation| equation.operator == ' ') {
+        return Err(ParseError::at_end(input, format!("missing operator for problem {}", i + 1)));
+    }
+
+    Ok(equations)
+}
+
+/// Lines are padded with spaces to the longest one, as editors tend to strip the trailing spaces.
+fn parse_two(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let Some(line_length) = input.lines().map(|line| line.chars().count()).max() else {
+        return Err(ParseError::at_end(input, "empty worksheet"));
+    };
+    input.lines().enumerate().map(|(line_num, line)| {
+        if let Some((i, c)) = line.char_indices().find(|(_, c)| !matches!(c, '0'..='9' | ' ' | '+' | '*')) {
+            return Err(ParseError::new(line_num, line, &line[i..i + c.len_utf8()], "expected a digit, `+`, `*` or space"));
+        }
+        let mut chars: Vec<char> = line.chars().collect();
+        chars.resize(line_length, ' ');
+        Ok(chars)
+    }).collect()
+}
+
+/// Part one reads the worksheet row by row, part two column by column, so both layouts are kept.
+#[derive(Debug)]
+pub struct Worksheet {
+    equations: Vec<Equation>,
+    lines: Vec<Vec<char>>,
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+        Ok(Worksheet {
+            equations: parse_one(input)?,
+            lines: parse_two(input)?,
+        })
+    }
+
+    fn part_one(worksheet: &Self::Input) -> u64 {
+        let mut result: u64 = 0;
+        for equation in &worksheet.equations {
+            match equation.operator {
+                '+' => { result += equation.operands.iter().sum::<u64>() }
+                '*' => { result += equation.operands.iter().product::<u64>(); }
+                _ => {panic!("Invalid operator");}
+            }
+        }
+        result
+    }
+
+    fn part_two(worksheet: &Self::Input) -> u64 {
+        let lines = &worksheet.lines;
+
+        let line_length = lines[0].len();
+        let mut result = 0;
+        let mut operands: Vec<u64> = vec![];
+        for i in (0..line_length).rev() {
+            let mut operand: u64 = 0;
+            for line in lines {
+                match line[i] {
+                    ' ' => {}
+                    '+' => {
+                        operands.push(operand);
+                        result += operands.iter().sum::<u64>();
+                        operands.clear();
+                        operand = 0;
+                    }
+                    '*' => {
+                        operands.push(operand);
+                        result += operands.iter().product::<u64>();
+                        operands.clear();
+                        operand = 0;
+                    }
+                    a => {
+                        let digit = a.to_digit(10).unwrap();
+                        operand *= 10;
+                        operand += digit as u64;
+                    }
+                }
+            }
+            if operand != 0 { operands.push(operand); }
+        }
+        result
+    }
+}
+
+utils::example_tests!(Day06);
@@ -0,0 +1,110 @@
+use std::collections::{HashMap, HashSet};
+use utils::parse::ParseError;
+use utils::params::Params;
+use utils::solution::Solution;
+
+#[derive(Debug)]
+pub struct TachyonManifold {
+    start_coord: usize,
+    splitter_coords: Vec<Vec<usize>>
+}
+
+impl TachyonManifold {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let mut start_coord = None;
+        let mut splitter_coords = vec![];
+        for (line_num, line) in input.lines().enumerate() {
+            let mut line_splitters = vec![];
+            for (i, char) in line.char_indices() {
+                match char {
+                    'S' => { start_coord = Some(i) }
+                    '.' => {}
+                    // A splitter on the edge would send half of its beam off the manifold.
+                    '^' if i == 0 || i + 1 == line.len() => return Err(ParseError::new(line_num, line, &line[i..i + 1], "splitter on the edge of the manifold")),
+                    '^' => { line_splitters.push(i) }
+                    _ => return Err(ParseError::new(line_num, line, &line[i..i + char.len_utf8()], "expected `S`, `.` or `^`"))
+                }
+            }
+            if !line_splitters.is_empty() {
+                splitter_coords.push(line_splitters);
+            }
+        }
+        let start_coord = start_coord.ok_or_else(|| ParseError::at_end(input, "missing start position `S`"))?;
+        Ok(TachyonManifold{start_coord, splitter_coords})
+    }
+
+    fn calculate_splits(&self) -> u64 {
+        let mut splits = 0;
+        let mut curr_rays: HashSet<usize> = HashSet::new();
+        curr_rays.insert(self.start_coord);
+        for splitter_coords in &self.splitter_coords {
+            for coord in splitter_coords {
+                if curr_rays.contains(coord) {
+                    splits += 1;
+                    curr_rays.remove(coord);
+                    curr_rays.insert(*coord + 1);
+                    curr_rays.insert(*coord - 1);
+                }
+            }
+        }
+        splits
+    }
+
+    fn calculate_quantum_splits(&self) -> u64 {
+        let mut curr_rays: HashMap<usize, usize> = HashMap::new();
+        curr_rays.insert(self.start_coord, 1);
+        for splitter_coords in &self.splitter_coords {
+            let mut next_rays: HashMap<usize, usize> = HashMap::new();
+            for current_coord in curr_rays.keys() {
+                let number_of_timelines = *curr_rays.get(current_coord).unwrap();
+                if splitter_coords.contains(current_coord) {
+                    *next_rays.entry(*current_coord-1).or_default() += number_of_timelines;
+                    *next_rays.entry(*current_coord+1).or_default() += number_of_timelines;
+                } else {
+                    *next_rays.entry(*current_coord).or_default() += number_of_timelines;
+                }
+            }
+            curr_rays = next_rays;
+        }
+        curr_rays.values().sum::<usize>() as u64
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = TachyonManifold;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+        TachyonManifold::new(input)
+    }
+
+    fn part_one(tachyon_manifold: &Self::Input) -> u64 {
+        tachyon_manifold.calculate_splits()
+    }
+
+    fn part_two(tachyon_manifold: &Self::Input) -> u64 {
+        tachyon_manifold.calculate_quantum_splits()
+    }
+}
+
+utils::example_tests!(Day07);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_input() {
+        let err = Day07::parse(".S.\n.^.\n^..\n", &Params::default()).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, "^"));
+        let err = Day07::parse(".S.\n..^\n", &Params::default()).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = Day07::parse("...\n.^.\n", &Params::default()).unwrap_err();
+        assert_eq!(err.line, 3);
+    }
+}
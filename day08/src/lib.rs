@@ -0,0 +1,113 @@
+use std::collections::{HashMap, HashSet};
+use petgraph::graph::UnGraph;
+use itertools::Itertools;
+use petgraph::algo::connected_components;
+use petgraph::visit::Walker;
+use utils::coord::{self, Coord3};
+use utils::parse::ParseError;
+use utils::params::{Param, Params};
+use utils::solution::Solution;
+
+type NodeIds = HashMap<usize, petgraph::graph::NodeIndex>;
+
+pub struct Playground {
+    coords: Vec<Coord3<i64>>,
+    graph: UnGraph::<usize, u64>,
+    node_ids: NodeIds,
+    connections: Vec<(u64, usize, usize)>,
+    connection_size: usize,
+}
+
+fn parse_input(input: &str, connection_size: usize) -> Result<Playground, ParseError> {
+    let coords: Vec<Coord3<i64>> = coord::parse_list(input)?;
+    let mut g = UnGraph::<usize, u64>::new_undirected();
+    let mut node_ids = HashMap::new();
+    for (id, _coord) in coords.iter().enumerate() {
+        node_ids.insert(id, g.add_node(id));
+    }
+    let connections = calc_connections(&coords);
+    Ok(Playground { coords, graph: g, node_ids, connections, connection_size })
+}
+
+fn calc_connections(coords: &[Coord3<i64>]) -> Vec<(u64, usize, usize)> {
+    let mut connections = Vec::new();
+
+    for coord_pair in coords.iter().enumerate().combinations(2) {
+        let (id1, c1) = coord_pair[0];
+        let (id2, c2) = coord_pair[1];
+        connections.push((c1.squared_euclidean_distance(c2), id1, id2));
+    }
+    connections.sort_by_key(|connection| connection.0);
+    connections
+}
+
+fn largest_circuits(playground: &Playground) -> usize {
+    let mut g = playground.graph.clone();
+    let node_ids = &playground.node_ids;
+
+    for connection in playground.connections.iter().take(playground.connection_size) {
+        g.add_edge(node_ids[&connection.1], node_ids[&connection.2], connection.0);
+    }
+
+    let mut visited = HashSet::new();
+    let mut component_sizes = Vec::new();
+    for coord in node_ids {
+        if visited.contains(coord.1) {
+            continue;
+        }
+        let bfs = petgraph::visit::Bfs::new(&g, *coord.1);
+        let mut size = 0;
+        for nx in bfs.iter(&g) {
+            visited.insert(nx);
+            size += 1;
+        }
+        component_sizes.push(size);
+    }
+    component_sizes.sort_unstable();
+    component_sizes.reverse();
+    component_sizes.iter().take(3).product()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "connections",
+        description: "number of closest pairs connected in part one",
+        example: 10,
+        real: 1000,
+        min: 0,
+    }];
+
+    type Input = Playground;
+    type PartOne = usize;
+    type PartTwo = u64;
+
+    fn parse(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        parse_input(input, params.get("connections") as usize)
+    }
+
+    fn part_one(playground: &Self::Input) -> usize {
+        largest_circuits(playground)
+    }
+
+    fn part_two(playground: &Self::Input) -> u64 {
+        let mut g = playground.graph.clone();
+        let node_ids = &playground.node_ids;
+        let coords = &playground.coords;
+
+        let mut connections = playground.connections.clone();
+        connections.reverse();
+
+        let mut res = 0u64;
+        while connected_components(&g) > 1 {
+            let c = connections.pop().unwrap();
+            g.add_edge(node_ids[&c.1], node_ids[&c.2], c.0);
+            res = coords[c.1].x as u64 * coords[c.2].x as u64
+        }
+        res
+    }
+}
+
+utils::example_tests!(Day08);
@@ -0,0 +1,64 @@
+use geo::Contains;
+use utils::coord::{self, Coord};
+use utils::parse::ParseError;
+use utils::params::Params;
+use utils::progress::Progress;
+use utils::solution::Solution;
+
+fn parse_input(input: &str) -> Result<Vec<Coord<i64>>, ParseError> {
+    coord::parse_list(input)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Coord<i64>>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(coords: &Self::Input) -> i64 {
+        let mut max = 0;
+        for coord in coords.iter() {
+            for other_coord in coords.iter() {
+                let size = (coord.x-other_coord.x + 1).abs() * (coord.y-other_coord.y + 1).abs();
+                max = max.max(size);
+            }
+        }
+        max
+    }
+
+    fn part_two(coords: &Self::Input) -> i64 {
+        let coords: Vec<geo::Coord> = coords.iter().map(|coord| geo::Coord{x: coord.x as f64, y: coord.y as f64}).collect();
+        let outline = geo::LineString::new(coords.clone());
+        let polygon = geo::Polygon::new(outline, vec![]);
+        let mut max = 0;
+        const EPSILON: f64 = 0.0001;
+        let cancel = utils::cancel::current();
+        let mut progress = Progress::new(coords.len());
+        for coord in coords.iter() {
+            if cancel.is_cancelled() {
+                break;
+            }
+            for other_coord in coords.iter() {
+                let top_left = geo::Coord{x: coord.x.min(other_coord.x), y: coord.y.min(other_coord.y)} + geo::Coord{x: EPSILON, y: EPSILON};
+                let bottom_right = geo::Coord{x: coord.x.max(other_coord.x), y: coord.y.max(other_coord.y)} + geo::Coord{x: -EPSILON, y: -EPSILON};
+                let rect = geo::Rect::new(top_left, bottom_right);
+                if polygon.contains(&rect) {
+                    let size = ((coord.x-other_coord.x).abs() + 1f64) * ((coord.y-other_coord.y).abs() + 1f64);
+                    max = max.max(size.round() as i64);
+                }
+            }
+            progress.tick();
+        }
+
+        max
+    }
+}
+
+utils::example_tests!(Day09);
@@ -0,0 +1,27 @@
+use utils::parse::ParseError;
+use utils::params::Params;
+use utils::solution::Solution;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = String;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(_input: &Self::Input) -> u64 {
+        0
+    }
+
+    fn part_two(_input: &Self::Input) -> u64 {
+        0
+    }
+}
+
+utils::example_tests!(Day11);
@@ -1,29 +1,3 @@
-fn part_one(_input: &str) -> u64 {
-    0
-}
-
-fn part_two(_input: &str) -> u64 {
-    0
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const EXAMPLE: &str = include_str!("example.txt");
-
-    #[test]
-    fn example_part_one() {
-        assert_eq!(part_one(EXAMPLE), 0);
-    }
-
-    #[test]
-    fn example_part_two() {
-        assert_eq!(part_two(EXAMPLE), 0);
-    }
-}
-
 fn main() {
-    const INPUT: &str = include_str!("input.txt");
-    println!("{} part one: {}", env!("CARGO_PKG_NAME"), part_one(INPUT));
-    println!("{} part two: {}", env!("CARGO_PKG_NAME"), part_two(INPUT));
+    utils::solution::run::<day11::Day11>();
 }
@@ -0,0 +1,150 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// A puzzle answer of any day, whatever integer type or string the day computes it as. Answers are
+/// equal when they display the same, as that is what the puzzle website compares.
+#[derive(Debug, Clone, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Number(number), Answer::Number(other)) => number == other,
+            (Answer::Text(text), Answer::Text(other)) => text == other,
+            _ => self.to_string() == other.to_string(),
+        }
+    }
+}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Answer::Number(number) => number.to_string().hash(state),
+            Answer::Text(text) => text.hash(state),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct EmptyAnswerError;
+
+impl fmt::Display for EmptyAnswerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "answer is empty")
+    }
+}
+
+impl std::error::Error for EmptyAnswerError {}
+
+impl FromStr for Answer {
+    type Err = EmptyAnswerError;
+
+    /// Integers written the way they display become [`Answer::Number`], anything else non-empty,
+    /// including `0123` or `+5`, [`Answer::Text`] so that it displays unchanged.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(EmptyAnswerError);
+        }
+        Ok(match input.parse::<i128>() {
+            Ok(number) if number.to_string() == input => Answer::Number(number),
+            _ => Answer::Text(input.to_string()),
+        })
+    }
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    matches!(self, Answer::Number(number) if *number == *other as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Answer::Number(number) => number.to_string() == other,
+            Answer::Text(text) => text == other,
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_display_round_trip() {
+        for text in ["0", "-42", "3121910778619", "LGTM"] {
+            assert_eq!(text.parse::<Answer>().unwrap().to_string(), text);
+        }
+        assert_eq!("".parse::<Answer>(), Err(EmptyAnswerError));
+
+        let text = Answer::from("0123".to_string());
+        let reloaded: Answer = text.to_string().parse().unwrap();
+        assert_eq!(reloaded, Answer::Text("0123".to_string()));
+        assert_eq!(reloaded, text);
+    }
+
+    #[test]
+    fn equal_when_displayed_the_same() {
+        assert_eq!(Answer::from("123"), Answer::from(123u32));
+        assert_ne!(Answer::from("0123"), Answer::from(123u32));
+        assert_ne!(Answer::from(123u32), "0123");
+        let set: std::collections::HashSet<Answer> = [Answer::from("42"), Answer::from(42u8)].into();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn compares_against_literals() {
+        assert_eq!(Answer::from(40usize), 40u64);
+        assert_eq!(Answer::from(-3i64), -3i32);
+        assert_eq!(Answer::from(25272u64), "25272");
+        assert_eq!(Answer::from("abc"), "abc");
+        assert_ne!(Answer::from(1u8), "abc");
+    }
+}
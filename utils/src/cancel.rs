@@ -0,0 +1,55 @@
+//! Cooperative cancellation of long-running parts. The runner gives each part a [`CancelToken`]
+//! and cancels it once the part runs out of time; solvers with hot loops fetch the token with
+//! [`current`] and poll it, returning early with whatever they have, as the answer of a cancelled
+//! part is discarded anyway.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        CancelToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    static TOKEN: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// Runs `step` with `token` as the [`current`] token of this thread.
+pub fn with_token<T>(token: CancelToken, step: impl FnOnce() -> T) -> T {
+    crate::scoped::with_value(&TOKEN, token, step)
+}
+
+/// The token of the part running on this thread, one that is never cancelled outside the runner.
+pub fn current() -> CancelToken {
+    TOKEN.with_borrow(Clone::clone).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn current_token() {
+        let token = CancelToken::new();
+        with_token(token.clone(), || {
+            assert!(!current().is_cancelled());
+            token.cancel();
+            assert!(current().is_cancelled());
+        });
+        assert!(!current().is_cancelled());
+    }
+}
@@ -1,57 +1,707 @@
-use std::ops::{Add, AddAssign, Mul, Sub};
+use crate::parse::{parse_field, ParseError};
+use std::fmt::{self, Debug};
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use std::str::FromStr;
 
-#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Ord, PartialOrd)]
-pub struct Coord {
-    pub x: i32,
-    pub y: i32,
+/// The primitive integers a [`Coord`] can be made of.
+pub trait Integer:
+    Copy
+    + Debug
+    + Eq
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    /// The unsigned type of the same width, which distances are measured in.
+    type Unsigned: Copy + Debug + Eq + Ord + Add<Output = Self::Unsigned> + Mul<Output = Self::Unsigned>;
+
+    const ONE: Self;
+
+    fn abs_diff(self, other: Self) -> Self::Unsigned;
+
+    /// Adds a unit step of -1, 0 or 1, or `None` when the result does not fit.
+    fn checked_step(self, step: i8) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($int:ty => $unsigned:ty),*) => {
+        $(
+            impl Integer for $int {
+                type Unsigned = $unsigned;
+
+                const ONE: Self = 1;
+
+                fn abs_diff(self, other: Self) -> $unsigned {
+                    <$int>::abs_diff(self, other)
+                }
+
+                fn checked_step(self, step: i8) -> Option<Self> {
+                    match step {
+                        -1 => self.checked_sub(1),
+                        0 => Some(self),
+                        1 => self.checked_add(1),
+                        _ => panic!("not a unit step: {step}"),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize
+);
+
+/// The signed integers, whose coordinates can be negated.
+pub trait Signed: Integer + Neg<Output = Self> {
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_signed {
+    ($($int:ty),*) => {
+        $(
+            impl Signed for $int {
+                fn abs(self) -> Self {
+                    <$int>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$int>::signum(self)
+                }
+            }
+        )*
+    };
 }
 
-impl Coord {
-    pub fn new(x: i32, y: i32) -> Self {
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Ord, PartialOrd, Default)]
+pub struct Coord<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Coord<T> {
+    pub fn new(x: T, y: T) -> Self {
         Coord { x, y }
     }
 
-    pub fn get_neighbours(&self) -> Vec<Coord> {
+    /// Converts to another integer type, or `None` when a component does not fit, e.g. a negative
+    /// coordinate as a `usize` index.
+    pub fn try_convert<U: TryFrom<T>>(self) -> Option<Coord<U>> {
+        Some(Coord::new(self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+
+    /// The `(column, row)` indices of this coordinate in a grid, if both are non-negative.
+    pub fn to_index(self) -> Option<Coord<usize>>
+    where
+        usize: TryFrom<T>,
+    {
+        self.try_convert()
+    }
+}
+
+impl<T: Integer> Coord<T> {
+    pub fn get_neighbours(&self) -> Vec<Coord<T>> {
         vec![
-            Self::new(self.x, self.y - 1),
-            Self::new(self.x, self.y + 1),
-            Self::new(self.x - 1, self.y),
-            Self::new(self.x + 1, self.y),
+            Self::new(self.x, self.y - T::ONE),
+            Self::new(self.x, self.y + T::ONE),
+            Self::new(self.x - T::ONE, self.y),
+            Self::new(self.x + T::ONE, self.y),
         ]
     }
 
-    pub fn distance(&self, rhs: &Coord) -> u32 {
+    /// The Manhattan distance.
+    pub fn distance(&self, rhs: &Coord<T>) -> T::Unsigned {
         self.x.abs_diff(rhs.x) + self.y.abs_diff(rhs.y)
     }
+
+    /// The largest difference along either axis, i.e. the number of king moves between the two.
+    pub fn chebyshev_distance(&self, rhs: &Coord<T>) -> T::Unsigned {
+        self.x.abs_diff(rhs.x).max(self.y.abs_diff(rhs.y))
+    }
+
+    /// The square of the Euclidean distance, which orders points the same way without floats.
+    pub fn squared_euclidean_distance(&self, rhs: &Coord<T>) -> T::Unsigned {
+        let (dx, dy) = (self.x.abs_diff(rhs.x), self.y.abs_diff(rhs.y));
+        dx * dx + dy * dy
+    }
+
+    pub fn dot(&self, rhs: &Coord<T>) -> T {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    /// The z component of the 3D cross product, positive when `rhs` is clockwise from `self` as
+    /// `y` grows downwards.
+    pub fn cross(&self, rhs: &Coord<T>) -> T {
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    /// The neighbour in `direction`, or `None` when it does not fit in `T`, e.g. left of `x = 0`
+    /// for grid indices.
+    pub fn checked_step(self, direction: impl Into<Direction8>) -> Option<Self> {
+        let (dx, dy) = direction.into().step();
+        Some(Coord::new(self.x.checked_step(dx)?, self.y.checked_step(dy)?))
+    }
+
+    /// The up to four neighbours sharing an edge, clockwise from the one above.
+    pub fn orthogonal_neighbours(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().filter_map(move |direction| self.checked_step(direction))
+    }
+
+    /// The up to four neighbours sharing only a corner, clockwise from the upper right one.
+    pub fn diagonal_neighbours(self) -> impl Iterator<Item = Self> {
+        Direction8::DIAGONALS.into_iter().filter_map(move |direction| self.checked_step(direction))
+    }
+
+    /// The up to eight surrounding coordinates, clockwise from the one above.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL.into_iter().filter_map(move |direction| self.checked_step(direction))
+    }
 }
 
-impl Sub for &Coord {
-    type Output = Coord;
+/// The four orthogonal directions, clockwise. As in grids, `y` grows downwards.
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Ord, PartialOrd)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        Coord::new(self.x - rhs.x, self.y - rhs.y)
+    /// The unit step in this direction, e.g. `(0, -1)` for up.
+    pub fn offset<T: From<i8>>(self) -> Coord<T> {
+        Direction8::from(self).offset()
     }
 }
 
-impl Add<Coord> for &Coord {
-    type Output = Coord;
+/// The eight directions to the surrounding coordinates, clockwise.
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Ord, PartialOrd)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub const DIAGONALS: [Direction8; 4] =
+        [Direction8::UpRight, Direction8::DownRight, Direction8::DownLeft, Direction8::UpLeft];
+
+    /// Turns by 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns by 45 degrees counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    fn step(self) -> (i8, i8) {
+        match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        }
+    }
 
-    fn add(self, rhs: Coord) -> Self::Output {
-        Coord::new(self.x + rhs.x, self.y + rhs.y)
+    /// The unit step in this direction, e.g. `(1, -1)` for up-right.
+    pub fn offset<T: From<i8>>(self) -> Coord<T> {
+        let (dx, dy) = self.step();
+        Coord::new(dx.into(), dy.into())
     }
 }
 
-impl AddAssign for Coord {
-    fn add_assign(&mut self, rhs: Coord) {
-        self.x += rhs.x;
-        self.y += rhs.y;
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Direction8::ALL[direction as usize * 2]
     }
 }
 
-impl Mul<i32> for Coord {
-    type Output = Coord;
+/// Lossless conversions, following the `From` impls between the primitive integers.
+macro_rules! impl_from {
+    ($($from:ty => [$($to:ty),*]),*) => {
+        $($(
+            impl From<Coord<$from>> for Coord<$to> {
+                fn from(coord: Coord<$from>) -> Self {
+                    Coord::new(coord.x.into(), coord.y.into())
+                }
+            }
+
+            impl From<Coord3<$from>> for Coord3<$to> {
+                fn from(coord: Coord3<$from>) -> Self {
+                    Coord3::new(coord.x.into(), coord.y.into(), coord.z.into())
+                }
+            }
+        )*)*
+    };
+}
+
+impl_from!(
+    i8 => [i16, i32, i64, i128, isize],
+    i16 => [i32, i64, i128, isize],
+    i32 => [i64, i128],
+    i64 => [i128],
+    u8 => [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize],
+    u16 => [u32, u64, u128, usize, i32, i64, i128],
+    u32 => [u64, u128, i64, i128],
+    u64 => [u128, i128]
+);
+
+/// A point in space, for 3D puzzles.
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Ord, PartialOrd, Default)]
+pub struct Coord3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Unit steps to the six neighbours sharing a face.
+const FACE_STEPS: [(i8, i8, i8); 6] = [(-1, 0, 0), (1, 0, 0), (0, -1, 0), (0, 1, 0), (0, 0, -1), (0, 0, 1)];
+
+impl<T> Coord3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Coord3 { x, y, z }
+    }
+
+    /// Converts to another integer type, or `None` when a component does not fit.
+    pub fn try_convert<U: TryFrom<T>>(self) -> Option<Coord3<U>> {
+        Some(Coord3::new(self.x.try_into().ok()?, self.y.try_into().ok()?, self.z.try_into().ok()?))
+    }
+}
+
+impl<T: Integer> Coord3<T> {
+    /// The Manhattan distance.
+    pub fn distance(&self, rhs: &Coord3<T>) -> T::Unsigned {
+        self.x.abs_diff(rhs.x) + self.y.abs_diff(rhs.y) + self.z.abs_diff(rhs.z)
+    }
+
+    /// The largest difference along any axis.
+    pub fn chebyshev_distance(&self, rhs: &Coord3<T>) -> T::Unsigned {
+        self.x.abs_diff(rhs.x).max(self.y.abs_diff(rhs.y)).max(self.z.abs_diff(rhs.z))
+    }
+
+    /// The square of the Euclidean distance, which orders points the same way without floats.
+    /// Mind the width: coordinates in the hundred thousands already need `i64`.
+    pub fn squared_euclidean_distance(&self, rhs: &Coord3<T>) -> T::Unsigned {
+        let (dx, dy, dz) = (self.x.abs_diff(rhs.x), self.y.abs_diff(rhs.y), self.z.abs_diff(rhs.z));
+        dx * dx + dy * dy + dz * dz
+    }
+
+    pub fn dot(&self, rhs: &Coord3<T>) -> T {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    pub fn cross(&self, rhs: &Coord3<T>) -> Coord3<T> {
+        Coord3::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+
+    fn checked_step(self, (dx, dy, dz): (i8, i8, i8)) -> Option<Self> {
+        Some(Coord3::new(self.x.checked_step(dx)?, self.y.checked_step(dy)?, self.z.checked_step(dz)?))
+    }
+
+    /// The up to six neighbours sharing a face.
+    pub fn orthogonal_neighbours(self) -> impl Iterator<Item = Self> {
+        FACE_STEPS.into_iter().filter_map(move |step| self.checked_step(step))
+    }
+
+    /// The up to 26 surrounding points, sharing a face, an edge or a corner.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        (0..27i8)
+            .filter(|&index| index != 13)
+            .map(|index| (index / 9 - 1, index / 3 % 3 - 1, index % 3 - 1))
+            .filter_map(move |step| self.checked_step(step))
+    }
+}
+
+/// Arithmetic and component-wise helpers shared by [`Coord`] and [`Coord3`]: addition and
+/// subtraction of coordinates and multiplication, division and remainder by a scalar, each for
+/// any mix of values and references.
+macro_rules! impl_ops {
+    ($coord:ident { $($component:ident),+ }) => {
+        impl_ops!(@coord $coord { $($component),+ } Add add AddAssign add_assign);
+        impl_ops!(@coord $coord { $($component),+ } Sub sub SubAssign sub_assign);
+        impl_ops!(@scalar $coord { $($component),+ } Mul mul MulAssign mul_assign);
+        impl_ops!(@scalar $coord { $($component),+ } Div div DivAssign div_assign);
+        impl_ops!(@scalar $coord { $($component),+ } Rem rem RemAssign rem_assign);
+
+        impl<T: Signed> Neg for $coord<T> {
+            type Output = $coord<T>;
+
+            fn neg(self) -> $coord<T> {
+                $coord { $($component: -self.$component),+ }
+            }
+        }
+
+        impl<T: Signed> Neg for &$coord<T> {
+            type Output = $coord<T>;
+
+            fn neg(self) -> $coord<T> {
+                -*self
+            }
+        }
+
+        impl<T: Integer> $coord<T> {
+            /// The smaller of each component, unlike `Ord::min` which compares whole coordinates.
+            pub fn component_min(self, rhs: Self) -> Self {
+                $coord { $($component: self.$component.min(rhs.$component)),+ }
+            }
+
+            /// The larger of each component, unlike `Ord::max` which compares whole coordinates.
+            pub fn component_max(self, rhs: Self) -> Self {
+                $coord { $($component: self.$component.max(rhs.$component)),+ }
+            }
+        }
+
+        impl<T: Signed> $coord<T> {
+            pub fn abs(self) -> Self {
+                $coord { $($component: self.$component.abs()),+ }
+            }
+
+            /// The sign of each component, e.g. to step towards another coordinate one unit at a time.
+            pub fn signum(self) -> Self {
+                $coord { $($component: self.$component.signum()),+ }
+            }
+        }
+    };
+    (@coord $coord:ident { $($component:ident),+ } $op:ident $method:ident $op_assign:ident $method_assign:ident) => {
+        impl<T: Integer> $op for $coord<T> {
+            type Output = $coord<T>;
+
+            fn $method(self, rhs: $coord<T>) -> $coord<T> {
+                $coord { $($component: self.$component.$method(rhs.$component)),+ }
+            }
+        }
+
+        impl<T: Integer> $op<&$coord<T>> for $coord<T> {
+            type Output = $coord<T>;
+
+            fn $method(self, rhs: &$coord<T>) -> $coord<T> {
+                self.$method(*rhs)
+            }
+        }
+
+        impl<T: Integer> $op<$coord<T>> for &$coord<T> {
+            type Output = $coord<T>;
+
+            fn $method(self, rhs: $coord<T>) -> $coord<T> {
+                (*self).$method(rhs)
+            }
+        }
+
+        impl<T: Integer> $op<&$coord<T>> for &$coord<T> {
+            type Output = $coord<T>;
+
+            fn $method(self, rhs: &$coord<T>) -> $coord<T> {
+                (*self).$method(*rhs)
+            }
+        }
+
+        impl<T: Integer> $op_assign for $coord<T> {
+            fn $method_assign(&mut self, rhs: $coord<T>) {
+                *self = (*self).$method(rhs);
+            }
+        }
+
+        impl<T: Integer> $op_assign<&$coord<T>> for $coord<T> {
+            fn $method_assign(&mut self, rhs: &$coord<T>) {
+                *self = (*self).$method(*rhs);
+            }
+        }
+    };
+    (@scalar $coord:ident { $($component:ident),+ } $op:ident $method:ident $op_assign:ident $method_assign:ident) => {
+        impl<T: Integer> $op<T> for $coord<T> {
+            type Output = $coord<T>;
+
+            fn $method(self, rhs: T) -> $coord<T> {
+                $coord { $($component: self.$component.$method(rhs)),+ }
+            }
+        }
+
+        impl<T: Integer> $op<&T> for $coord<T> {
+            type Output = $coord<T>;
+
+            fn $method(self, rhs: &T) -> $coord<T> {
+                self.$method(*rhs)
+            }
+        }
+
+        impl<T: Integer> $op<T> for &$coord<T> {
+            type Output = $coord<T>;
+
+            fn $method(self, rhs: T) -> $coord<T> {
+                (*self).$method(rhs)
+            }
+        }
+
+        impl<T: Integer> $op<&T> for &$coord<T> {
+            type Output = $coord<T>;
+
+            fn $method(self, rhs: &T) -> $coord<T> {
+                (*self).$method(*rhs)
+            }
+        }
+
+        impl<T: Integer> $op_assign<T> for $coord<T> {
+            fn $method_assign(&mut self, rhs: T) {
+                *self = (*self).$method(rhs);
+            }
+        }
+
+        impl<T: Integer> $op_assign<&T> for $coord<T> {
+            fn $method_assign(&mut self, rhs: &T) {
+                *self = (*self).$method(*rhs);
+            }
+        }
+    };
+}
+
+impl_ops!(Coord { x, y });
+impl_ops!(Coord3 { x, y, z });
+
+/// Parses exactly `N` comma-separated values. Errors are reported on line 1, with the column of
+/// the offending value within `s`.
+fn parse_components<T, const N: usize>(s: &str) -> Result<[T; N], ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let fields: Vec<&str> = s.split(',').collect();
+    if fields.len() != N {
+        return Err(ParseError::new(0, s, s, format!("expected {N} comma-separated values")));
+    }
+    let values: Vec<T> = fields.iter().map(|field| parse_field(0, s, field.trim())).collect::<Result<_, _>>()?;
+    match values.try_into() {
+        Ok(components) => Ok(components),
+        Err(_) => unreachable!("one value per field"),
+    }
+}
+
+/// Parses the puzzle format `x,y`.
+impl<T: FromStr> FromStr for Coord<T>
+where
+    T::Err: fmt::Display,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let [x, y] = parse_components(s)?;
+        Ok(Coord::new(x, y))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Coord<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Parses the puzzle format `x,y,z`.
+impl<T: FromStr> FromStr for Coord3<T>
+where
+    T::Err: fmt::Display,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let [x, y, z] = parse_components(s)?;
+        Ok(Coord3::new(x, y, z))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Coord3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// Parses one coordinate per line, e.g. `Vec<Coord<i64>>` from lines like `7,1`, reporting the line
+/// and column of the first invalid one.
+pub fn parse_list<C: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<C>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| {
+            line.parse().map_err(|err: ParseError| ParseError { line: line_index + 1, ..err })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        let coord: Coord<i64> = Coord::new(-3, 4).into();
+        assert_eq!(coord, Coord::<i64>::new(-3, 4));
+        assert_eq!(Coord::<u32>::new(3, 4).try_convert::<i64>(), Some(Coord::new(3, 4)));
+        assert_eq!(Coord::new(3, 4).to_index(), Some(Coord::<usize>::new(3, 4)));
+        assert_eq!(Coord::new(-1, 4).to_index(), None);
+        assert_eq!(Coord::<i64>::new(1 << 40, 0).try_convert::<i32>(), None);
+    }
+
+    #[test]
+    fn neighbours() {
+        let coord = Coord::new(3, 4);
+        let orthogonal: Vec<Coord> = coord.orthogonal_neighbours().collect();
+        assert_eq!(orthogonal, [Coord::new(3, 3), Coord::new(4, 4), Coord::new(3, 5), Coord::new(2, 4)]);
+        assert_eq!(coord.diagonal_neighbours().count(), 4);
+        assert!(coord.neighbours().all(|neighbour| (neighbour - coord).x.abs() <= 1 && neighbour != coord));
+
+        // Grid indices have no neighbours left of or above the origin.
+        let corner: Vec<Coord<usize>> = Coord::new(0, 0).neighbours().collect();
+        assert_eq!(corner, [Coord::new(1, 0), Coord::new(1, 1), Coord::new(0, 1)]);
+    }
+
+    #[test]
+    fn directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!(Direction::Down.offset::<i64>(), Coord::new(0, 1));
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownRight.opposite(), Direction8::UpLeft);
+        assert_eq!(Direction8::from(Direction::Left), Direction8::Left);
+        assert_eq!(Direction8::UpRight.offset::<i32>(), Coord::new(1, -1));
+        for direction in Direction8::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.offset::<i32>() + direction.opposite().offset(), Coord::new(0, 0));
+        }
+    }
+
+    #[test]
+    fn operators() {
+        let a = Coord::new(7, -3);
+        let b = Coord::new(2, 5);
+        assert_eq!(a + b, Coord::new(9, 2));
+        // Operators on references, spelled out as clippy prefers values where they are `Copy`.
+        assert_eq!(Add::add(&a, b), Add::add(a, &b));
+        assert_eq!(Sub::sub(&a, &b), Coord::new(5, -8));
+        assert_eq!(-a, Coord::new(-7, 3));
+        assert_eq!(Neg::neg(&a), a * -1);
+        assert_eq!(Mul::mul(&a, 2), Coord::new(14, -6));
+        assert_eq!(a / 2, Coord::new(3, -1));
+        assert_eq!(Rem::rem(a, &4), Coord::new(3, -3));
+
+        let mut c = a;
+        c += b;
+        c -= &a;
+        c *= 3;
+        c /= 2;
+        c %= 4;
+        assert_eq!(c, Coord::new(3, 3));
+    }
+
+    #[test]
+    fn component_wise() {
+        let a = Coord::new(7, -3);
+        let b = Coord::new(2, 5);
+        assert_eq!(a.component_min(b), Coord::new(2, -3));
+        assert_eq!(a.component_max(b), Coord::new(7, 5));
+        assert_eq!(a.abs(), Coord::new(7, 3));
+        assert_eq!((b - a).signum(), Coord::new(-1, 1));
+        assert_eq!(a.dot(&b), 14 - 15);
+        assert_eq!(Coord::new(1, 0).cross(&Coord::new(0, 1)), 1);
+        assert_eq!(a.chebyshev_distance(&b), 8u32);
+        assert_eq!(a.squared_euclidean_distance(&b), 25 + 64u32);
+
+        let x = Coord3::new(1, 0, 0);
+        let y = Coord3::new(0, 1, 0);
+        assert_eq!(x.cross(&y), Coord3::new(0, 0, 1));
+        assert_eq!(x.dot(&y), 0);
+        assert_eq!(-(x + y) * 2, Coord3::new(-2, -2, 0));
+        assert_eq!(Coord3::new(3, -4, 5).signum(), Coord3::new(1, -1, 1));
+    }
+
+    #[test]
+    fn coord3() {
+        let a = Coord3::<i64>::new(162, 817, 812);
+        let b = Coord3::new(425, 690, 689);
+        assert_eq!(a.distance(&b), 263 + 127 + 123);
+        assert_eq!(a.chebyshev_distance(&b), 263);
+        assert_eq!(a.squared_euclidean_distance(&b), 263 * 263 + 127 * 127 + 123 * 123);
+        assert_eq!(Add::add(&Sub::sub(&b, &a), a), b);
+        assert_eq!(Coord3::from(Coord3::<i32>::new(1, 2, 3)) * 2, Coord3::<i64>::new(2, 4, 6));
+
+        let origin = Coord3::<i32>::new(0, 0, 0);
+        assert_eq!(origin.orthogonal_neighbours().count(), 6);
+        assert!(origin.orthogonal_neighbours().all(|neighbour| neighbour.distance(&origin) == 1));
+        assert_eq!(origin.neighbours().count(), 26);
+        assert!(origin.neighbours().all(|neighbour| neighbour.chebyshev_distance(&origin) == 1));
+        assert_eq!(Coord3::<usize>::new(0, 0, 0).neighbours().count(), 7);
+    }
+
+    #[test]
+    fn distances_in_any_width() {
+        assert_eq!(Coord::new(-1, 2).distance(&Coord::new(2, -2)), 7u32);
+        assert_eq!(Coord::<i64>::new(0, 1 << 40).distance(&Coord::new(0, 0)), 1u64 << 40);
+        assert_eq!(Coord::<usize>::new(1, 5).distance(&Coord::new(4, 2)), 6usize);
+    }
+
+    #[test]
+    fn parsing_and_display() {
+        let coord: Coord<i64> = "-3,4".parse().unwrap();
+        assert_eq!(coord, Coord::new(-3, 4));
+        assert_eq!(coord.to_string(), "-3,4");
+        let coord: Coord3 = "162,817,812".parse().unwrap();
+        assert_eq!(coord.to_string(), "162,817,812");
 
-    fn mul(self, rhs: i32) -> Self::Output {
-        Coord::new(self.x * rhs, self.y * rhs)
+        let err = "1,2,3".parse::<Coord>().unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (1, "expected 2 comma-separated values"));
+        let err = parse_list::<Coord3<u8>>("1,2,3\n4,300,6\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "300"));
+        assert_eq!(parse_list::<Coord>("7,1\n11,1").unwrap(), [Coord::new(7, 1), Coord::new(11, 1)]);
     }
 }
@@ -0,0 +1,158 @@
+//! Example tests driven by a per-day `src/manifest.txt`, one expectation per line:
+//!
+//! ```text
+//! # file        part  answer  params
+//! example.txt   1     40
+//! example.txt   2     25272
+//! large.txt     1     1234    connections=100
+//! ```
+//!
+//! Parameters not listed take the day's example defaults. Adding an edge case is a matter of
+//! dropping a file next to `example.txt` and listing it.
+
+use crate::answer::Answer;
+use crate::params::Params;
+use crate::solution::Solution;
+use std::path::Path;
+
+pub const MANIFEST_FILE: &str = "manifest.txt";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub file: String,
+    pub part: u8,
+    pub expected: Answer,
+    pub params: Params,
+}
+
+pub fn parse_manifest(manifest: &str) -> Result<Vec<Example>, String> {
+    let mut examples = vec![];
+    for (line_num, line) in manifest.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        let (Some(file), Some(part), Some(expected)) = (fields.next(), fields.next(), fields.next()) else {
+            return Err(format!("line {}: expected `file part answer [params]`", line_num + 1));
+        };
+        let part = match part {
+            "1" => 1,
+            "2" => 2,
+            _ => return Err(format!("line {}: invalid part `{part}`", line_num + 1)),
+        };
+        let expected = expected.parse().map_err(|err| format!("line {}: {}", line_num + 1, err))?;
+        let params = fields
+            .collect::<Vec<_>>()
+            .join(" ")
+            .parse()
+            .map_err(|err| format!("line {}: {}", line_num + 1, err))?;
+        examples.push(Example { file: file.to_string(), part, expected, params });
+    }
+    Ok(examples)
+}
+
+/// Runs every example listed in `dir/manifest.txt` and panics with all mismatches at once. An
+/// empty manifest passes with a note.
+pub fn check<S: Solution>(dir: &Path) {
+    let manifest_path = dir.join(MANIFEST_FILE);
+    let manifest = std::fs::read_to_string(&manifest_path)
+        .unwrap_or_else(|err| panic!("cannot read {}: {}", manifest_path.display(), err));
+    let examples = parse_manifest(&manifest).unwrap_or_else(|err| panic!("{}: {}", manifest_path.display(), err));
+    // A freshly scaffolded day has nothing to check yet, which should not fail the workspace.
+    if examples.is_empty() {
+        println!("{} lists no examples yet, nothing to check", manifest_path.display());
+        return;
+    }
+
+    let mut failures = vec![];
+    for example in &examples {
+        let path = dir.join(&example.file);
+        let raw = std::fs::read_to_string(&path).unwrap_or_else(|err| panic!("cannot read {}: {}", path.display(), err));
+        let (input, _) = crate::input::normalize(&raw);
+        let mut params = Params::defaults(S::PARAMS, true);
+        if let Err(err) = params.apply(&example.params, S::PARAMS) {
+            failures.push(format!("{} part {}: {}", example.file, example.part, err));
+            continue;
+        }
+        let answer = match S::parse(&input, &params) {
+            Ok(parsed) => match example.part {
+                1 => S::part_one(&parsed).into(),
+                _ => S::part_two(&parsed).into(),
+            },
+            Err(err) => {
+                failures.push(format!("{} part {}: {}", example.file, example.part, err.in_day(S::DAY)));
+                continue;
+            }
+        };
+        println!("{} part {}: {}", example.file, example.part, answer);
+        if answer != example.expected {
+            failures.push(format!(
+                "{} part {}: expected {}, got {}",
+                example.file, example.part, example.expected, answer
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "day{:02} examples failed:\n{}", S::DAY, failures.join("\n"));
+}
+
+/// Generates the test that checks a day against its `src/manifest.txt`.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ident) => {
+        #[cfg(test)]
+        mod example_tests {
+            #[test]
+            fn examples() {
+                $crate::examples::check::<super::$solution>(&::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src"));
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_manifest_lines() {
+        let examples = parse_manifest("# comment\nexample.txt 1 40 connections=10\n\nsmall.txt 2 abc\n").unwrap();
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].params.get("connections"), 10);
+        assert_eq!((examples[1].file.as_str(), examples[1].part), ("small.txt", 2));
+        assert_eq!(examples[1].expected, "abc");
+        assert!(parse_manifest("example.txt 3 1").is_err());
+        assert!(parse_manifest("example.txt 1").is_err());
+    }
+
+    struct Unsolved;
+
+    impl Solution for Unsolved {
+        const DAY: u8 = 0;
+
+        type Input = ();
+        type PartOne = u64;
+        type PartTwo = u64;
+
+        fn parse(_input: &str, _params: &Params) -> Result<Self::Input, crate::parse::ParseError> {
+            Ok(())
+        }
+
+        fn part_one(_input: &Self::Input) -> u64 {
+            0
+        }
+
+        fn part_two(_input: &Self::Input) -> u64 {
+            0
+        }
+    }
+
+    #[test]
+    fn empty_manifest_passes() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(MANIFEST_FILE), "# file            part  answer  params\n").unwrap();
+        check::<Unsolved>(&dir);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
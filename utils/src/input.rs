@@ -0,0 +1,204 @@
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Resolves the input of `day`: an explicit path (`-` meaning stdin) wins, then
+    /// `$AOC_INPUT_DIR/dayNN.txt`, then the legacy `dayNN/src/input.txt` in the workspace.
+    pub fn resolve(day: u8, arg: Option<&str>) -> Self {
+        match arg {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => match std::env::var_os(INPUT_DIR_VAR) {
+                Some(dir) => InputSource::File(Path::new(&dir).join(format!("day{day:02}.txt"))),
+                None => InputSource::File(workspace_root().join(format!("day{day:02}")).join("src").join("input.txt")),
+            },
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::File(path) => std::fs::read_to_string(path).map_err(|err| match err.kind() {
+                std::io::ErrorKind::NotFound => InputError::NotFound(path.clone()),
+                _ => InputError::Io(self.clone(), err),
+            }),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input).map_err(|err| InputError::Io(self.clone(), err))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io(InputSource, std::io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(
+                f,
+                "input file {} not found (pass a path, `-` for stdin, or set {INPUT_DIR_VAR})",
+                path.display()
+            ),
+            InputError::Io(source, err) => write!(f, "cannot read input from {source}: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// What [`normalize`] had to change to turn an input into plain LF-terminated lines.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Normalization {
+    pub removed_bom: bool,
+    pub crlf_line_endings: usize,
+    pub trimmed_lines: usize,
+    pub trailing_blank_lines: usize,
+}
+
+impl Normalization {
+    pub fn is_empty(&self) -> bool {
+        *self == Normalization::default()
+    }
+}
+
+impl fmt::Display for Normalization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut changes = vec![];
+        if self.removed_bom {
+            changes.push("removed UTF-8 BOM".to_string());
+        }
+        if self.crlf_line_endings > 0 {
+            changes.push(format!("converted {} CRLF line ending(s)", self.crlf_line_endings));
+        }
+        if self.trimmed_lines > 0 {
+            changes.push(format!("trimmed trailing whitespace on {} line(s)", self.trimmed_lines));
+        }
+        if self.trailing_blank_lines > 0 {
+            changes.push(format!("removed {} trailing blank line(s)", self.trailing_blank_lines));
+        }
+        if changes.is_empty() {
+            write!(f, "nothing to normalize")
+        } else {
+            write!(f, "{}", changes.join(", "))
+        }
+    }
+}
+
+/// Strips a UTF-8 BOM, CRLF line endings, trailing whitespace on each line and trailing blank
+/// lines. A non-empty result always ends with a single newline, which is not worth reporting.
+pub fn normalize(raw: &str) -> (String, Normalization) {
+    let mut normalization = Normalization::default();
+    let input = match raw.strip_prefix('\u{feff}') {
+        Some(stripped) => {
+            normalization.removed_bom = true;
+            stripped
+        }
+        None => raw,
+    };
+
+    let mut lines = vec![];
+    for line in input.split_inclusive('\n') {
+        let line = match line.strip_suffix("\r\n") {
+            Some(line) => {
+                normalization.crlf_line_endings += 1;
+                line
+            }
+            None => line.strip_suffix('\n').unwrap_or(line),
+        };
+        let trimmed = line.trim_end();
+        if trimmed.len() != line.len() {
+            normalization.trimmed_lines += 1;
+        }
+        lines.push(trimmed);
+    }
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+        normalization.trailing_blank_lines += 1;
+    }
+
+    let mut normalized = lines.join("\n");
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    (normalized, normalization)
+}
+
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+/// Reads and normalizes the input of `day` as selected by the first command line argument,
+/// exiting with a readable message when it is unavailable. Meant for the per-day binaries.
+pub fn read_input_or_exit(day: u8) -> String {
+    let arg = std::env::args().nth(1);
+    match InputSource::resolve(day, arg.as_deref()).read() {
+        Ok(input) => normalize(&input).0,
+        Err(err) => {
+            eprintln!("day{day:02}: {err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clean_input_is_untouched() {
+        let (normalized, normalization) = normalize("3-5\n\n1\n");
+        assert_eq!(normalized, "3-5\n\n1\n");
+        assert!(normalization.is_empty());
+    }
+
+    #[test]
+    fn windows_input() {
+        let (normalized, normalization) = normalize("\u{feff}L68 \r\nR48\r\n\r\n\r\n");
+        assert_eq!(normalized, "L68\nR48\n");
+        assert_eq!(
+            normalization,
+            Normalization {
+                removed_bom: true,
+                crlf_line_endings: 4,
+                trimmed_lines: 1,
+                trailing_blank_lines: 2,
+            }
+        );
+        assert_eq!(
+            normalization.to_string(),
+            "removed UTF-8 BOM, converted 4 CRLF line ending(s), trimmed trailing whitespace on 1 line(s), removed 2 trailing blank line(s)"
+        );
+    }
+
+    #[test]
+    fn missing_final_newline() {
+        let (normalized, normalization) = normalize("11-22,95-115");
+        assert_eq!(normalized, "11-22,95-115\n");
+        assert!(normalization.is_empty());
+        assert_eq!(normalize("\n\n").0, "");
+    }
+}
@@ -1,2 +1,11 @@
+pub mod answer;
+pub mod cancel;
 pub mod coord;
+pub mod examples;
+pub mod input;
 pub mod matrix;
+pub mod params;
+pub mod parse;
+pub mod progress;
+mod scoped;
+pub mod solution;
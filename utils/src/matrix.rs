@@ -1,7 +1,18 @@
+use crate::coord::Coord;
+
 pub fn parse_matrix(input: &str) -> Vec<Vec<char>> {
     input.lines().map(|line| line.chars().collect()).collect()
 }
 
+/// The cell at `coord`, `x` being the column and `y` the row, or `None` outside the matrix.
+pub fn get<T, C>(matrix: &[Vec<T>], coord: Coord<C>) -> Option<&T>
+where
+    usize: TryFrom<C>,
+{
+    let index = coord.to_index()?;
+    matrix.get(index.y)?.get(index.x)
+}
+
 pub fn transpose_matrix<T>(original: Vec<Vec<T>>) -> Vec<Vec<T>> {
     assert!(!original.is_empty());
     let mut transposed = (0..original[0].len()).map(|_| vec![]).collect::<Vec<_>>();
@@ -0,0 +1,122 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// A parameter a day declares in `Solution::PARAMS`, e.g. how many pairs day08 connects.
+/// Examples are usually smaller than the real input, so each has its own default.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Param {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub example: i64,
+    pub real: i64,
+    pub min: i64,
+}
+
+/// Values of named integer parameters of a puzzle.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Params {
+    values: BTreeMap<String, i64>,
+}
+
+impl Params {
+    pub fn defaults(declared: &[Param], example: bool) -> Self {
+        let mut params = Params::default();
+        for param in declared {
+            params.set(param.name, if example { param.example } else { param.real });
+        }
+        params
+    }
+
+    /// Applies `overrides` on top of the current values, rejecting undeclared names and values
+    /// below the declared minimum.
+    pub fn apply(&mut self, overrides: &Params, declared: &[Param]) -> Result<(), String> {
+        for (name, value) in overrides.iter() {
+            let Some(param) = declared.iter().find(|param| param.name == name) else {
+                let known: Vec<&str> = declared.iter().map(|param| param.name).collect();
+                return Err(format!("unknown parameter `{name}` (known: {})", known.join(", ")));
+            };
+            if value < param.min {
+                return Err(format!("parameter `{name}` must be at least {}", param.min));
+            }
+            self.set(name, value);
+        }
+        Ok(())
+    }
+
+    /// The value of a declared parameter. Params handed to `Solution::parse` are built from
+    /// `Solution::PARAMS`, so a missing one is a bug in the day.
+    pub fn get(&self, name: &str) -> i64 {
+        match self.values.get(name) {
+            Some(value) => *value,
+            None => panic!("parameter `{name}` is not declared in Solution::PARAMS"),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i64)> {
+        self.values.iter().map(|(name, value)| (name.as_str(), *value))
+    }
+
+    pub fn set(&mut self, name: &str, value: i64) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl FromStr for Params {
+    type Err = String;
+
+    /// Parses `name=value` assignments separated by commas or whitespace.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+        for assignment in input.split(|c: char| c == ',' || c.is_whitespace()).filter(|s| !s.is_empty()) {
+            let (name, value) = assignment
+                .split_once('=')
+                .ok_or_else(|| format!("expected `name=value`, found `{assignment}`"))?;
+            let value = value
+                .parse()
+                .map_err(|err| format!("invalid value for parameter `{name}`: {err}"))?;
+            params.set(name, value);
+        }
+        Ok(params)
+    }
+}
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let assignments: Vec<String> = self.values.iter().map(|(name, value)| format!("{name}={value}")).collect();
+        write!(f, "{}", assignments.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECLARED: [Param; 1] = [Param { name: "connections", description: "", example: 10, real: 1000, min: 0 }];
+
+    #[test]
+    fn defaults_and_overrides() {
+        assert_eq!(Params::defaults(&DECLARED, true).get("connections"), 10);
+        let mut params = Params::defaults(&DECLARED, false);
+        assert_eq!(params.get("connections"), 1000);
+        params.apply(&"connections=2000".parse().unwrap(), &DECLARED).unwrap();
+        assert_eq!(params.get("connections"), 2000);
+        assert_eq!(params.to_string(), "connections=2000");
+    }
+
+    #[test]
+    fn rejects_invalid_overrides() {
+        let mut params = Params::defaults(&DECLARED, false);
+        assert_eq!(
+            params.apply(&"pairs=1".parse().unwrap(), &DECLARED),
+            Err("unknown parameter `pairs` (known: connections)".to_string())
+        );
+        assert!(params.apply(&"connections=-1".parse().unwrap(), &DECLARED).is_err());
+        assert!("connections".parse::<Params>().is_err());
+        assert!("connections=x".parse::<Params>().is_err());
+    }
+}
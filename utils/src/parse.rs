@@ -0,0 +1,98 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Where and why a puzzle input could not be parsed. Lines and columns are 1-based.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error for `offending`, which should be a slice of `line` so the column can be
+    /// derived from its position. `line_index` is 0-based, as produced by `lines().enumerate()`.
+    pub fn new(line_index: usize, line: &str, offending: &str, message: impl Into<String>) -> Self {
+        let line_start = line.as_ptr() as usize;
+        let offset = (offending.as_ptr() as usize)
+            .checked_sub(line_start)
+            .filter(|offset| offset + offending.len() <= line.len())
+            .or_else(|| line.find(offending))
+            .unwrap_or(0);
+        ParseError {
+            day: None,
+            line: line_index + 1,
+            column: line[..offset].chars().count() + 1,
+            text: offending.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// An error about the input as a whole, e.g. a missing section, reported after its last line.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        ParseError::new(input.lines().count(), "", "", message)
+    }
+
+    pub fn in_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day{day:02}: ")?;
+        }
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+        if !self.text.is_empty() {
+            write!(f, " (found `{}`)", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `field`, a slice of `line`, reporting its position on failure.
+pub fn parse_field<T>(line_index: usize, line: &str, field: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    field.parse().map_err(|err| ParseError::new(line_index, line, field, format!("invalid value: {err}")))
+}
+
+/// Splits `field`, a slice of `line`, at the first `separator`, reporting a missing separator.
+pub fn split_field<'a>(line_index: usize, line: &str, field: &'a str, separator: char) -> Result<(&'a str, &'a str), ParseError> {
+    field
+        .split_once(separator)
+        .ok_or_else(|| ParseError::new(line_index, line, field, format!("expected `{separator}`")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_from_slice_position() {
+        let line = "12,x4,5";
+        let field = line.split(',').nth(1).unwrap();
+        let err = parse_field::<u32>(2, line, field).unwrap_err().in_day(8);
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 4, "x4"));
+        assert_eq!(
+            err.to_string(),
+            "day08: line 3, column 4: invalid value: invalid digit found in string (found `x4`)"
+        );
+    }
+
+    #[test]
+    fn missing_separator_and_end_of_input() {
+        let err = split_field(0, "R12", "R12", ',').unwrap_err();
+        assert_eq!((err.line, err.column, err.message.as_str()), (1, 1, "expected `,`"));
+        let err = ParseError::at_end("a\nb\n", "missing ids");
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+}
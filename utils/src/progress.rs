@@ -0,0 +1,74 @@
+//! Progress of long-running parts. A solver creates a [`Progress`] and ticks it as it goes; the
+//! caller running the part decides how that is shown by installing a [`ProgressHook`] with
+//! [`with_hook`]. Without a hook, as in tests and the per-day binaries, ticking does nothing.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+pub trait ProgressHook: Send + Sync {
+    fn update(&self, done: usize, total: usize);
+}
+
+thread_local! {
+    static HOOK: RefCell<Option<Arc<dyn ProgressHook>>> = const { RefCell::new(None) };
+}
+
+/// Runs `step` with `hook` receiving the progress reported on this thread.
+pub fn with_hook<T>(hook: Arc<dyn ProgressHook>, step: impl FnOnce() -> T) -> T {
+    crate::scoped::with_value(&HOOK, hook, step)
+}
+
+/// Counts completed units of work out of a known total.
+pub struct Progress {
+    done: usize,
+    total: usize,
+    hook: Option<Arc<dyn ProgressHook>>,
+}
+
+impl Progress {
+    pub fn new(total: usize) -> Self {
+        let hook = HOOK.with_borrow(Clone::clone);
+        if let Some(hook) = &hook {
+            hook.update(0, total);
+        }
+        Progress { done: 0, total, hook }
+    }
+
+    pub fn tick(&mut self) {
+        self.advance(1);
+    }
+
+    pub fn advance(&mut self, units: usize) {
+        self.done = (self.done + units).min(self.total);
+        if let Some(hook) = &self.hook {
+            hook.update(self.done, self.total);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<(usize, usize)>>);
+
+    impl ProgressHook for Recorder {
+        fn update(&self, done: usize, total: usize) {
+            self.0.lock().unwrap().push((done, total));
+        }
+    }
+
+    #[test]
+    fn reports_to_the_installed_hook_only() {
+        let recorder = Arc::new(Recorder::default());
+        with_hook(recorder.clone(), || {
+            let mut progress = Progress::new(3);
+            progress.tick();
+            progress.advance(5);
+        });
+        Progress::new(2).tick();
+        assert_eq!(*recorder.0.lock().unwrap(), [(0, 3), (1, 3), (3, 3)]);
+    }
+}
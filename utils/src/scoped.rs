@@ -0,0 +1,46 @@
+//! Thread-local values set for the duration of a call, as the runner does for the cancellation
+//! token and the progress hook of a part.
+
+use std::cell::RefCell;
+use std::thread::LocalKey;
+
+/// Runs `step` with `slot` holding `value`, restoring the previous value afterwards, also when
+/// `step` panics.
+pub(crate) fn with_value<V: 'static, T>(
+    slot: &'static LocalKey<RefCell<Option<V>>>,
+    value: V,
+    step: impl FnOnce() -> T,
+) -> T {
+    struct Restore<V: 'static> {
+        slot: &'static LocalKey<RefCell<Option<V>>>,
+        previous: Option<V>,
+    }
+
+    impl<V> Drop for Restore<V> {
+        fn drop(&mut self) {
+            self.slot.set(self.previous.take());
+        }
+    }
+
+    let _restore = Restore { slot, previous: slot.replace(Some(value)) };
+    step()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    thread_local! {
+        static VALUE: RefCell<Option<u8>> = const { RefCell::new(None) };
+    }
+
+    #[test]
+    fn restores_the_previous_value() {
+        with_value(&VALUE, 1, || {
+            let panicked = std::panic::catch_unwind(|| with_value(&VALUE, 2, || panic!("step failed")));
+            assert!(panicked.is_err());
+            assert_eq!(VALUE.with_borrow(Clone::clone), Some(1));
+        });
+        assert_eq!(VALUE.with_borrow(Clone::clone), None);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::answer::Answer;
+use crate::params::{Param, Params};
+use crate::parse::ParseError;
+
+/// A day's puzzle. The input is parsed once and the parsed form is shared by both parts.
+pub trait Solution {
+    const DAY: u8;
+    const PARAMS: &'static [Param] = &[];
+
+    /// Shared by both parts, which may run on threads of their own.
+    type Input: Send + Sync + 'static;
+    type PartOne: Into<Answer>;
+    type PartTwo: Into<Answer>;
+
+    /// `params` holds the puzzle parameters, e.g. sizes that differ between examples and real inputs.
+    fn parse(input: &str, params: &Params) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+}
+
+/// Entry point of the per-day binaries: reads the input and prints both answers.
+pub fn run<S: Solution>() {
+    let input = match S::parse(&crate::input::read_input_or_exit(S::DAY), &Params::defaults(S::PARAMS, false)) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err.in_day(S::DAY));
+            std::process::exit(1);
+        }
+    };
+    println!("day{:02} part one: {}", S::DAY, S::part_one(&input).into());
+    println!("day{:02} part two: {}", S::DAY, S::part_two(&input).into());
+}